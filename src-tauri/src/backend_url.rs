@@ -80,6 +80,9 @@ pub fn resolve_backend_port(config_path: Option<&PathBuf>) -> u16 {
 }
 
 /// Returns backend base URL for use in commands (config port, then env, then default).
+/// Every command module resolves the URL through this function rather than hardcoding
+/// `127.0.0.1:8778`, so a port reassigned by `ensure_python_backend_running` (see
+/// `write_backend_port_to_config`) is picked up on the very next call.
 pub fn get_backend_base_url(app: &tauri::AppHandle) -> String {
     let port = if let Some(cfg) = get_config_path_from_app(app) {
         read_backend_port_from_config(&cfg).unwrap_or_else(get_backend_port_from_env)
@@ -1,83 +1,120 @@
-use std::process::Command;
+use reqwest::Method;
+
 use crate::backend_url::get_backend_base_url;
+use crate::commands::http;
+use crate::commands::secrets;
+
+/// Resolves the key to send to the backend: an explicit `api_key` always wins (so existing
+/// flows that haven't migrated to the encrypted store keep working), otherwise we look up a
+/// previously-stored, encrypted secret for `platform` so the frontend never has to hold the
+/// raw key.
+fn resolve_api_key(app: &tauri::AppHandle, api_key: Option<String>, platform: Option<&str>) -> String {
+    if let Some(key) = api_key {
+        if !key.is_empty() {
+            return key;
+        }
+    }
+    platform.and_then(|p| secrets::resolve_api_key(app, p)).unwrap_or_default()
+}
+
+fn build_annotations_payload(
+    knowledge_points: &[String],
+    api_key: String,
+    model: String,
+    base_url: Option<String>,
+    platform: Option<String>,
+    candidate_count: Option<i32>,
+) -> serde_json::Value {
+    let mut payload = serde_json::json!({
+        "knowledge_points": knowledge_points,
+        "api_key": api_key,
+        "model": model,
+        "base_url": base_url.unwrap_or_default(),
+    });
+    if let Some(p) = platform {
+        payload["platform"] = serde_json::json!(p);
+    }
+    payload["candidate_count"] = serde_json::json!(candidate_count.unwrap_or(1).clamp(1, 10));
+    payload
+}
 
 #[tauri::command]
 pub async fn generate_annotations(
     app: tauri::AppHandle,
     knowledge_points: Vec<String>,
-    api_key: String,
+    api_key: Option<String>,
     model: String,
     base_url: Option<String>,
     platform: Option<String>,
     candidate_count: Option<i32>,
 ) -> Result<String, String> {
+    let resolved_key = resolve_api_key(&app, api_key, platform.as_deref());
+    let payload = build_annotations_payload(&knowledge_points, resolved_key, model, base_url, platform, candidate_count);
+    http::backend_json(&app, Method::POST, "/annotations/generate", None, Some(payload)).await
+}
+
+/// Streaming variant of `generate_annotations`. Opens an SSE connection to the backend and
+/// forwards each `data:` chunk to the frontend over `on_event` as candidates are produced,
+/// instead of blocking until the whole candidate set finishes.
+#[tauri::command]
+pub async fn generate_annotations_stream(
+    app: tauri::AppHandle,
+    knowledge_points: Vec<String>,
+    api_key: Option<String>,
+    model: String,
+    base_url: Option<String>,
+    platform: Option<String>,
+    candidate_count: Option<i32>,
+    on_event: tauri::ipc::Channel<serde_json::Value>,
+) -> Result<(), String> {
+    let resolved_key = resolve_api_key(&app, api_key, platform.as_deref());
+    let mut payload = build_annotations_payload(&knowledge_points, resolved_key, model, base_url, platform, candidate_count);
+    payload["stream"] = serde_json::json!(true);
+
     let backend_base = get_backend_base_url(&app);
-    let kp_json = serde_json::to_string(&knowledge_points).unwrap_or_else(|_| "[]".to_string());
-    let kp_escaped = kp_json.replace('\\', "\\\\").replace('"', "\\\"");
-    let api_key_escaped = api_key.replace('\\', "\\\\").replace('"', "\\\"");
-    let model_escaped = model.replace('\\', "\\\\").replace('"', "\\\"");
-    let base_url_val = base_url.unwrap_or_default();
-    let base_url_escaped = base_url_val.replace('\\', "\\\\").replace('"', "\\\"");
-    let platform_val = platform.unwrap_or_default();
-    let platform_escaped = platform_val.replace('\\', "\\\\").replace('"', "\\\"");
-    let candidate_count_val = candidate_count.unwrap_or(1).clamp(1, 10);
-    let backend_base_escaped = backend_base.replace('\\', "\\\\").replace('"', "\\\"");
+    let url = format!("{}/annotations/generate", backend_base);
+    let client = reqwest::Client::new();
 
-    let python_script = format!(
-        r#"
-import sys
-import requests
-import json
+    let mut resp = client
+        .post(&url)
+        .json(&payload)
+        .send()
+        .await
+        .map_err(|e| format!("Failed to reach backend: {}", e))?;
 
-backend_base = "{}"
-kp_json = "{}"
-knowledge_points = json.loads(kp_json) if kp_json else []
-api_key = "{}"
-model = "{}"
-base_url = "{}"
-platform = "{}"
-candidate_count = {}
+    if !resp.status().is_success() {
+        let body = resp.text().await.unwrap_or_default();
+        return Err(format!("Backend returned an error: {}", body));
+    }
 
-payload = {{"knowledge_points": knowledge_points, "api_key": api_key, "model": model, "base_url": base_url if base_url else None}}
-if platform:
-    payload["platform"] = platform
-payload["candidate_count"] = candidate_count
+    let mut index: u32 = 0;
+    let mut carry = String::new();
+    while let Some(chunk) = resp.chunk().await.map_err(|e| format!("Stream read failed: {}", e))? {
+        carry.push_str(&String::from_utf8_lossy(&chunk));
 
-try:
-    response = requests.post(
-        backend_base + '/annotations/generate',
-        json=payload
-    )
-    
-    result = {{
-        "success": response.status_code == 200,
-        "data": response.json() if response.status_code == 200 else None,
-        "error": None if response.status_code == 200 else response.text
-    }}
-    print(json.dumps(result))
-except Exception as e:
-    result = {{
-        "success": False,
-        "data": None,
-        "error": str(e)
-    }}
-    print(json.dumps(result))
-"#,
-        backend_base_escaped,
-        kp_escaped,
-        api_key_escaped,
-        model_escaped,
-        base_url_escaped,
-        platform_escaped,
-        candidate_count_val
-    );
+        while let Some(pos) = carry.find('\n') {
+            let line = carry[..pos].trim().to_string();
+            carry.drain(..=pos);
 
-    let output = Command::new("python")
-        .arg("-c")
-        .arg(&python_script)
-        .output()
-        .map_err(|e| format!("Failed to execute Python: {}", e))?;
+            let Some(data) = line.strip_prefix("data:") else {
+                continue;
+            };
+            let data = data.trim();
+            if data.is_empty() || data == "[DONE]" {
+                continue;
+            }
+            let Ok(candidate) = serde_json::from_str::<serde_json::Value>(data) else {
+                continue;
+            };
+            let _ = on_event.send(serde_json::json!({
+                "index": index,
+                "candidate": candidate,
+                "done": false,
+            }));
+            index += 1;
+        }
+    }
 
-    let output_str = String::from_utf8_lossy(&output.stdout);
-    Ok(output_str.to_string())
+    let _ = on_event.send(serde_json::json!({ "index": index, "candidate": null, "done": true }));
+    Ok(())
 }
@@ -1,19 +1,75 @@
+use std::collections::VecDeque;
 use std::fs;
 use std::path::PathBuf;
 use std::process::Command;
+use std::sync::Mutex;
 use std::time::Duration;
 
 #[cfg(windows)]
 use std::os::windows::process::CommandExt;
+#[cfg(not(windows))]
+use std::os::unix::process::CommandExt as _;
 
 use crate::backend_url::{
     configure_python_env, find_backend_executable_path, find_main_py_path, get_config_path_from_app,
     get_backend_port_from_env, resolve_backend_port,
 };
-use crate::state::{BackendProcess, BackendState};
+use crate::state::{BackendProcess, BackendState, BackendSupervisorState, SupervisedPhase, SupervisorSnapshot};
 #[cfg(windows)]
 use crate::state::JobHandleGuard;
 
+const SUPERVISOR_POLL_INTERVAL: Duration = Duration::from_secs(5);
+const SUPERVISOR_FAILURE_THRESHOLD: u32 = 3;
+const SUPERVISOR_MAX_BACKOFF: Duration = Duration::from_secs(30);
+/// Consecutive failed restart attempts (not health-check misses) before the supervisor gives up
+/// and reports `SupervisedPhase::Failed` instead of retrying forever.
+const SUPERVISOR_MAX_RETRIES: u32 = 6;
+const BACKEND_LOG_CAPACITY: usize = 500;
+const HEALTH_POLL_INTERVAL: Duration = Duration::from_millis(200);
+/// Bound on how long `ensure_python_backend_running` waits for a freshly-spawned backend to
+/// answer `/health` before giving up and reporting a startup failure.
+const STARTUP_WAIT: Duration = Duration::from_secs(5);
+/// Bound on how long `stop_backend_process` waits for a polite SIGTERM to take effect before
+/// escalating to SIGKILL.
+const SHUTDOWN_WAIT: Duration = Duration::from_millis(500);
+const SHUTDOWN_POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+/// Bounded ring buffer of a supervised child's interleaved stdout/stderr lines, fed by the
+/// reader threads spawned alongside it. Lines are also forwarded to the tracing log (so they
+/// land in the rotating file under the app's log dir) and emitted as a per-stream Tauri event
+/// for an in-app console; this buffer backs `get_backend_process_logs`/`get_ollama_process_logs`,
+/// which an in-app console can poll instead of re-reading the whole log file.
+#[derive(Default)]
+pub struct BackendLogBuffer(Mutex<VecDeque<String>>);
+
+impl BackendLogBuffer {
+    fn push(&self, line: String) {
+        if let Ok(mut buf) = self.0.lock() {
+            buf.push_back(line);
+            if buf.len() > BACKEND_LOG_CAPACITY {
+                buf.pop_front();
+            }
+        }
+    }
+
+    fn snapshot(&self) -> Vec<String> {
+        self.0.lock().map(|buf| buf.iter().cloned().collect()).unwrap_or_default()
+    }
+}
+
+/// Implemented by `BackendLogBuffer` and `ollama::OllamaLogBuffer` so `spawn_output_reader` can
+/// be shared between the two supervised children instead of duplicating the reader-thread
+/// plumbing per process.
+pub(crate) trait ProcessLogSink: Send + Sync + 'static {
+    fn push_line(&self, line: String);
+}
+
+impl ProcessLogSink for BackendLogBuffer {
+    fn push_line(&self, line: String) {
+        self.push(line);
+    }
+}
+
 #[cfg(windows)]
 const CREATE_NO_WINDOW: u32 = 0x08000000;
 
@@ -56,23 +112,218 @@ pub fn kill_process_on_port(port: u16) {
     }
     #[cfg(not(windows))]
     {
-        let _ = port;
+        let pids = pids_listening_on_port(port);
+        for pid in pids {
+            unsafe {
+                libc::kill(pid, libc::SIGTERM);
+            }
+        }
+        std::thread::sleep(Duration::from_millis(500));
+        for pid in pids_listening_on_port(port) {
+            unsafe {
+                libc::kill(pid, libc::SIGKILL);
+            }
+        }
+    }
+}
+
+/// PIDs of processes with an open listening socket on `port`, via `lsof` (falling back to
+/// `fuser` when `lsof` isn't installed).
+#[cfg(not(windows))]
+fn pids_listening_on_port(port: u16) -> Vec<i32> {
+    if let Ok(output) = Command::new("lsof").args(["-ti", &format!("tcp:{}", port)]).output() {
+        if output.status.success() {
+            return String::from_utf8_lossy(&output.stdout)
+                .lines()
+                .filter_map(|line| line.trim().parse::<i32>().ok())
+                .collect();
+        }
+    }
+    if let Ok(output) = Command::new("fuser").arg(format!("{}/tcp", port)).output() {
+        return String::from_utf8_lossy(&output.stdout)
+            .split_whitespace()
+            .filter_map(|tok| tok.trim_end_matches(char::is_alphabetic).parse::<i32>().ok())
+            .collect();
+    }
+    Vec::new()
+}
+
+/// Kills an entire process group (the backend child and any subprocesses it spawned) by
+/// sending the negative pgid to `kill(2)`, SIGTERM first then SIGKILL after a grace period
+/// if anything in the group is still alive. Used when a backend that never became healthy
+/// needs to go away immediately; `stop_backend_process` is the polite counterpart for a
+/// backend that's already up and serving requests.
+#[cfg(not(windows))]
+fn kill_process_group(pgid: i32) {
+    unsafe {
+        libc::kill(-pgid, libc::SIGTERM);
+    }
+    std::thread::sleep(SHUTDOWN_WAIT);
+    unsafe {
+        libc::kill(-pgid, libc::SIGKILL);
+    }
+}
+
+/// Asks a running backend to exit cleanly (SIGTERM to its process group on Unix, `taskkill`
+/// without `/F` on Windows) so it can flush its SQLite DB and close file handles, and only
+/// force-kills it if it's still alive after `SHUTDOWN_WAIT`.
+fn stop_backend_process(proc_: &mut BackendProcess) {
+    #[cfg(windows)]
+    {
+        let _ = Command::new("taskkill")
+            .args(["/PID", &proc_.child.id().to_string()])
+            .creation_flags(CREATE_NO_WINDOW)
+            .output();
+        let deadline = std::time::Instant::now() + SHUTDOWN_WAIT;
+        while std::time::Instant::now() < deadline {
+            if matches!(proc_.child.try_wait(), Ok(Some(_))) {
+                return;
+            }
+            std::thread::sleep(SHUTDOWN_POLL_INTERVAL);
+        }
+    }
+    #[cfg(not(windows))]
+    if let Some(pgid) = proc_.pgid {
+        unsafe {
+            libc::kill(-pgid, libc::SIGTERM);
+        }
+        let deadline = std::time::Instant::now() + SHUTDOWN_WAIT;
+        while std::time::Instant::now() < deadline {
+            if matches!(proc_.child.try_wait(), Ok(Some(_))) {
+                return;
+            }
+            std::thread::sleep(SHUTDOWN_POLL_INTERVAL);
+        }
+        unsafe {
+            libc::kill(-pgid, libc::SIGKILL);
+        }
     }
+    let _ = proc_.child.kill();
+}
+
+/// Forwards each line of a piped stdout/stderr stream to the tracing log, the `B` ring
+/// buffer (`BackendLogBuffer` for the Python backend, `OllamaLogBuffer` for the Ollama
+/// sidecar), and `log_event`, so the child's own output is visible instead of silently
+/// discarded. Generic over the sink so the backend and Ollama share this one reader instead of
+/// each process keeping a copy-pasted thread.
+pub(crate) fn spawn_output_reader<B: ProcessLogSink>(
+    app: Option<tauri::AppHandle>,
+    log_event: &'static str,
+    stream_name: &'static str,
+    stream: impl std::io::Read + Send + 'static,
+) {
+    std::thread::spawn(move || {
+        use std::io::BufRead;
+        let reader = std::io::BufReader::new(stream);
+        for line in reader.lines().map_while(Result::ok) {
+            if stream_name == "stderr" {
+                tracing::warn!(stream = stream_name, "{}", line);
+            } else {
+                tracing::info!(stream = stream_name, "{}", line);
+            }
+            if let Some(app) = &app {
+                use tauri::{Emitter, Manager};
+                if let Some(buffer) = app.try_state::<B>() {
+                    buffer.push_line(format!("[{}] {}", stream_name, line));
+                }
+                let _ = app.emit(log_event, &line);
+            }
+        }
+    });
 }
 
+#[derive(serde::Serialize, Clone)]
+struct ProcessTerminated {
+    pid: Option<u32>,
+    exit_code: Option<i32>,
+    signalled: bool,
+}
+
+/// Best-effort description of why a supervised child is being torn down, peeked via
+/// `try_wait` right before the supervisor takes it out of its `Mutex` to restart it — so the
+/// frontend learns whether the process crashed on its own or is being stopped because it went
+/// unhealthy while still running.
+pub(crate) fn emit_terminated(app: &tauri::AppHandle, event: &'static str, child: &mut std::process::Child) {
+    use tauri::Emitter;
+    let pid = Some(child.id());
+    let (exit_code, signalled) = match child.try_wait() {
+        Ok(Some(status)) => (status.code(), status.code().is_none()),
+        _ => (None, false),
+    };
+    let _ = app.emit(event, ProcessTerminated { pid, exit_code, signalled });
+}
+
+/// Last few stderr lines seen so far, pulled from the `BackendLogBuffer` (populated by the
+/// reader thread spawned alongside the child) rather than the child's own `stderr` handle,
+/// which is owned by that thread once it starts reading.
+fn stderr_tail_from_buffer(app: Option<&tauri::AppHandle>) -> String {
+    use tauri::Manager;
+    let Some(app) = app else { return String::new() };
+    let Some(buffer) = app.try_state::<BackendLogBuffer>() else { return String::new() };
+    buffer
+        .snapshot()
+        .into_iter()
+        .filter(|line| line.starts_with("[stderr]"))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Polls `/health` every `HEALTH_POLL_INTERVAL` until it succeeds or `STARTUP_WAIT` elapses,
+/// so a backend that needs a second or two to bind its port isn't declared dead prematurely.
+/// Uses `tokio::time::sleep` rather than `std::thread::sleep` since this runs on the async
+/// runtime shared with every other command.
+#[tracing::instrument(skip(client))]
 async fn wait_backend_healthy(client: &reqwest::Client, health_url: &str) -> bool {
-    for _ in 0..25 {
+    let deadline = std::time::Instant::now() + STARTUP_WAIT;
+    loop {
         if let Ok(resp) = client.get(health_url).send().await {
             if resp.status().is_success() {
                 return true;
             }
         }
-        std::thread::sleep(Duration::from_millis(200));
+        if std::time::Instant::now() >= deadline {
+            return false;
+        }
+        tokio::time::sleep(HEALTH_POLL_INTERVAL).await;
+    }
+}
+
+/// Distinguishes a process that exited with a code from one killed by a signal (the latter
+/// has no code on Unix), and reports whatever it printed to stderr (already captured by the
+/// `spawn_output_reader` thread, since `child.stderr` itself was taken when that thread was
+/// started) so a crash looks different from a valid-but-empty health response.
+fn describe_backend_failure(child: &mut std::process::Child, entry: &str, stderr_tail: &str) -> String {
+    match child.try_wait() {
+        Ok(Some(status)) => {
+            let reason = match status.code() {
+                Some(code) => format!("exited with code {}", code),
+                None => "terminated by signal".to_string(),
+            };
+            tracing::error!(entry, stderr = %stderr_tail, "python backend {}", reason);
+            format!(
+                "Python backend {} before becoming healthy. Entry: {}{}",
+                reason,
+                entry,
+                if stderr_tail.trim().is_empty() {
+                    String::new()
+                } else {
+                    format!("\nstderr: {}", stderr_tail.trim())
+                }
+            )
+        }
+        Ok(None) => {
+            tracing::error!(entry, stderr = %stderr_tail, "python backend still running but never became healthy");
+            format!("Python backend failed to become healthy. Entry: {}", entry)
+        }
+        Err(e) => {
+            tracing::error!(entry, error = %e, "failed to check python backend exit status");
+            format!("Python backend failed to become healthy. Entry: {}", entry)
+        }
     }
-    false
 }
 
 pub async fn ensure_python_backend_running(
+    app: Option<&tauri::AppHandle>,
     config_path: Option<PathBuf>,
 ) -> Result<Option<BackendProcess>, String> {
     let main_py_path = find_main_py_path();
@@ -101,9 +352,9 @@ pub async fn ensure_python_backend_running(
     } else {
         return Err("Python backend main.py not found. Please ensure python-backend/main.py exists or build the backend exe.".to_string());
     };
-    let backend_port = resolve_backend_port(config_path.as_ref());
+    let mut backend_port = resolve_backend_port(config_path.as_ref());
     configure_python_env(&backend_dir, backend_port);
-    let health_url = format!("http://127.0.0.1:{}/health", backend_port);
+    let mut health_url = format!("http://127.0.0.1:{}/health", backend_port);
 
     let client = reqwest::Client::builder()
         .timeout(Duration::from_secs(2))
@@ -119,6 +370,27 @@ pub async fn ensure_python_backend_running(
     kill_process_on_port(backend_port);
     std::thread::sleep(Duration::from_millis(500));
 
+    // Whatever was listening on the configured port didn't go away (e.g. it's owned by an
+    // unrelated process, or `kill_process_on_port` found no pid for it) — fall back
+    // to a fresh port rather than spawning into a collision, persist it, and let the frontend
+    // know so in-flight `get_backend_base_url` callers pick up the change.
+    if !crate::backend_url::is_port_available(backend_port) {
+        let new_port = crate::backend_url::pick_available_backend_port(backend_port.wrapping_add(1));
+        if new_port != backend_port {
+            tracing::warn!(old_port = backend_port, new_port, "backend port occupied, reassigning");
+            if let Some(ref cfg) = config_path {
+                let _ = crate::backend_url::write_backend_port_to_config(cfg, new_port);
+            }
+            backend_port = new_port;
+            configure_python_env(&backend_dir, backend_port);
+            health_url = format!("http://127.0.0.1:{}/health", backend_port);
+            if let Some(app) = app {
+                use tauri::Emitter;
+                let _ = app.emit("backend-port-changed", backend_port);
+            }
+        }
+    }
+
     #[cfg(windows)]
     {
         use std::os::windows::io::AsRawHandle;
@@ -159,16 +431,23 @@ pub async fn ensure_python_backend_running(
                 }
             }
         }
+        cmd.stdout(std::process::Stdio::piped());
+        cmd.stderr(std::process::Stdio::piped());
         let mut child = cmd
             .spawn()
             .map_err(|e| format!("Failed to start Python backend: {}", e))?;
+        if let Some(stdout) = child.stdout.take() {
+            spawn_output_reader::<BackendLogBuffer>(app.cloned(), "backend://stdout", "stdout", stdout);
+        }
+        if let Some(stderr) = child.stderr.take() {
+            spawn_output_reader::<BackendLogBuffer>(app.cloned(), "backend://stderr", "stderr", stderr);
+        }
         let healthy = wait_backend_healthy(&client, &health_url).await;
         if !healthy {
+            let stderr_tail = stderr_tail_from_buffer(app);
+            let message = describe_backend_failure(&mut child, &backend_entry_path.to_string_lossy(), &stderr_tail);
             let _ = child.kill();
-            return Err(format!(
-                "Python backend failed to become healthy. Entry: {}",
-                backend_entry_path.to_string_lossy()
-            ));
+            return Err(message);
         }
 
         let job_handle = unsafe { CreateJobObjectW(None, PCWSTR::null()) }
@@ -240,25 +519,41 @@ pub async fn ensure_python_backend_running(
                 }
             }
         }
+        cmd.stdout(std::process::Stdio::piped());
+        cmd.stderr(std::process::Stdio::piped());
+        // Make the child its own session/process-group leader so stopping it can signal the
+        // whole group (and anything it spawned) instead of just the one pid.
+        unsafe {
+            cmd.pre_exec(|| {
+                libc::setsid();
+                Ok(())
+            });
+        }
         let mut child = cmd
             .spawn()
             .map_err(|e| format!("Failed to start Python backend: {}", e))?;
+        let pgid = child.id() as i32;
+        if let Some(stdout) = child.stdout.take() {
+            spawn_output_reader::<BackendLogBuffer>(app.cloned(), "backend://stdout", "stdout", stdout);
+        }
+        if let Some(stderr) = child.stderr.take() {
+            spawn_output_reader::<BackendLogBuffer>(app.cloned(), "backend://stderr", "stderr", stderr);
+        }
         let healthy = wait_backend_healthy(&client, &health_url).await;
         if !healthy {
-            let _ = child.kill();
-            return Err(format!(
-                "Python backend failed to become healthy. Entry: {}",
-                backend_entry_path.to_string_lossy()
-            ));
+            let stderr_tail = stderr_tail_from_buffer(app);
+            let message = describe_backend_failure(&mut child, &backend_entry_path.to_string_lossy(), &stderr_tail);
+            kill_process_group(pgid);
+            return Err(message);
         }
-        Ok(Some(BackendProcess { child }))
+        Ok(Some(BackendProcess { child, pgid: Some(pgid) }))
     }
 }
 
 #[tauri::command]
 pub async fn start_python_backend(app: tauri::AppHandle) -> Result<String, String> {
     let config_path = get_config_path_from_app(&app);
-    match ensure_python_backend_running(config_path).await {
+    match ensure_python_backend_running(Some(&app), config_path).await {
         Ok(None) => Ok("Python backend already running".to_string()),
         Ok(Some(_)) => Ok("Python backend started".to_string()),
         Err(e) => Err(e),
@@ -268,8 +563,230 @@ pub async fn start_python_backend(app: tauri::AppHandle) -> Result<String, Strin
 #[tauri::command]
 pub async fn stop_python_backend(state: tauri::State<'_, BackendState>) -> Result<String, String> {
     if let Ok(mut guard) = state.process.lock() {
-        *guard = None;
+        if let Some(mut proc_) = guard.take() {
+            stop_backend_process(&mut proc_);
+        }
     }
     kill_process_on_port(get_backend_port_from_env());
     Ok("Python backend stopped".to_string())
 }
+
+fn unix_ms_now() -> Option<u64> {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .ok()
+        .map(|d| d.as_millis() as u64)
+}
+
+fn set_supervisor_phase(app: &tauri::AppHandle, phase: SupervisedPhase, pid: Option<u32>) {
+    use tauri::{Emitter, Manager};
+    if let Some(supervisor) = app.try_state::<BackendSupervisorState>() {
+        if let Ok(mut snapshot) = supervisor.snapshot.lock() {
+            snapshot.phase = phase;
+            if pid.is_some() {
+                snapshot.pid = pid;
+            }
+            if phase == SupervisedPhase::Ready {
+                snapshot.last_healthy_unix_ms = unix_ms_now();
+                snapshot.last_error = None;
+            }
+            if phase == SupervisedPhase::Restarting {
+                snapshot.restarts += 1;
+                snapshot.last_restart_unix_ms = unix_ms_now();
+            }
+            if phase == SupervisedPhase::Stopped || phase == SupervisedPhase::Failed {
+                snapshot.pid = None;
+            }
+            let _ = app.emit("backend-status", snapshot.clone());
+        }
+    }
+}
+
+/// Records the reason behind a `Degraded`/`Failed` transition, for `get_backend_status`
+/// to surface instead of just a bare phase name.
+fn set_supervisor_error(app: &tauri::AppHandle, message: impl Into<String>) {
+    use tauri::{Emitter, Manager};
+    if let Some(supervisor) = app.try_state::<BackendSupervisorState>() {
+        if let Ok(mut snapshot) = supervisor.snapshot.lock() {
+            snapshot.last_error = Some(message.into());
+            let _ = app.emit("backend-status", snapshot.clone());
+        }
+    }
+}
+
+/// Polls `/health` on an interval and restarts the backend with capped exponential backoff
+/// after `SUPERVISOR_FAILURE_THRESHOLD` consecutive misses, giving up (reporting `Failed`)
+/// after `SUPERVISOR_MAX_RETRIES` consecutive failed restart attempts rather than retrying
+/// forever. Runs for the lifetime of the app.
+#[tracing::instrument(skip(app))]
+pub async fn run_backend_supervisor(app: tauri::AppHandle) {
+    use tauri::Manager;
+
+    let backend_port = get_backend_port_from_env();
+    let health_url = format!("http://127.0.0.1:{}/health", backend_port);
+    let client = reqwest::Client::builder()
+        .timeout(Duration::from_secs(2))
+        .build()
+        .unwrap_or_else(|_| reqwest::Client::new());
+
+    let mut consecutive_failures: u32 = 0;
+    let mut consecutive_restart_failures: u32 = 0;
+    let mut backoff = Duration::from_millis(500);
+    let mut gave_up = false;
+
+    loop {
+        tokio::time::sleep(SUPERVISOR_POLL_INTERVAL).await;
+
+        let healthy = client
+            .get(&health_url)
+            .send()
+            .await
+            .map(|r| r.status().is_success())
+            .unwrap_or(false);
+
+        if healthy {
+            if consecutive_failures > 0 || gave_up {
+                tracing::info!(consecutive_failures, "python backend recovered");
+            }
+            consecutive_failures = 0;
+            consecutive_restart_failures = 0;
+            backoff = Duration::from_millis(500);
+            gave_up = false;
+            set_supervisor_phase(&app, SupervisedPhase::Ready, None);
+            continue;
+        }
+
+        if gave_up {
+            // Already reported `Failed`; keep polling for a manual or external recovery but
+            // stop hammering `ensure_python_backend_running`.
+            continue;
+        }
+
+        consecutive_failures += 1;
+        if consecutive_failures < SUPERVISOR_FAILURE_THRESHOLD {
+            set_supervisor_phase(&app, SupervisedPhase::Degraded, None);
+            continue;
+        }
+
+        tracing::warn!(consecutive_failures, "python backend unhealthy, restarting");
+        set_supervisor_phase(&app, SupervisedPhase::Restarting, None);
+
+        if let Some(state) = app.try_state::<BackendState>() {
+            if let Ok(mut guard) = state.process.lock() {
+                if let Some(mut proc_) = guard.take() {
+                    emit_terminated(&app, "backend://terminated", &mut proc_.child);
+                    stop_backend_process(&mut proc_);
+                }
+            }
+        }
+        kill_process_on_port(backend_port);
+
+        let config_path = get_config_path_from_app(&app);
+        match ensure_python_backend_running(Some(&app), config_path).await {
+            Ok(Some(proc_)) => {
+                let pid = proc_.child.id();
+                if let Some(state) = app.try_state::<BackendState>() {
+                    if let Ok(mut guard) = state.process.lock() {
+                        *guard = Some(proc_);
+                    }
+                }
+                consecutive_failures = 0;
+                consecutive_restart_failures = 0;
+                backoff = Duration::from_millis(500);
+                set_supervisor_phase(&app, SupervisedPhase::Ready, Some(pid));
+            }
+            Ok(None) => {
+                consecutive_failures = 0;
+                consecutive_restart_failures = 0;
+                set_supervisor_phase(&app, SupervisedPhase::Ready, None);
+            }
+            Err(e) => {
+                consecutive_restart_failures += 1;
+                tracing::error!(error = %e, consecutive_restart_failures, "supervisor restart attempt failed");
+                if consecutive_restart_failures >= SUPERVISOR_MAX_RETRIES {
+                    tracing::error!(consecutive_restart_failures, "giving up on python backend restarts");
+                    gave_up = true;
+                    set_supervisor_phase(&app, SupervisedPhase::Failed, None);
+                    set_supervisor_error(&app, e);
+                    continue;
+                }
+                set_supervisor_phase(&app, SupervisedPhase::Degraded, None);
+                set_supervisor_error(&app, e);
+                tokio::time::sleep(backoff).await;
+                backoff = (backoff * 2).min(SUPERVISOR_MAX_BACKOFF);
+            }
+        }
+    }
+}
+
+/// Live one-shot probe of `/health`, as opposed to `get_backend_status`'s cached supervisor
+/// snapshot — useful right before a command that can't tolerate the brief staleness window
+/// between supervisor poll ticks.
+#[tauri::command]
+pub async fn backend_health() -> Result<bool, String> {
+    let port = get_backend_port_from_env();
+    let health_url = format!("http://127.0.0.1:{}/health", port);
+    let client = reqwest::Client::builder()
+        .timeout(Duration::from_secs(3))
+        .build()
+        .map_err(|e| e.to_string())?;
+    Ok(wait_backend_healthy_once(&client, &health_url).await)
+}
+
+async fn wait_backend_healthy_once(client: &reqwest::Client, health_url: &str) -> bool {
+    matches!(client.get(health_url).send().await, Ok(resp) if resp.status().is_success())
+}
+
+/// Recent backend stdout/stderr lines from the in-memory ring buffer, for an in-app console —
+/// distinct from `logs::get_backend_logs`, which tails this app's own tracing log file rather
+/// than the backend subprocess's output.
+#[tauri::command]
+pub fn get_backend_process_logs(buffer: tauri::State<'_, BackendLogBuffer>) -> Result<Vec<String>, String> {
+    Ok(buffer.snapshot())
+}
+
+#[tauri::command]
+pub fn get_backend_status(
+    supervisor: tauri::State<'_, BackendSupervisorState>,
+) -> Result<SupervisorSnapshot, String> {
+    supervisor
+        .snapshot
+        .lock()
+        .map(|s| s.clone())
+        .map_err(|_| "Backend status lock poisoned".to_string())
+}
+
+#[tauri::command]
+pub async fn restart_backend(app: tauri::AppHandle, state: tauri::State<'_, BackendState>) -> Result<String, String> {
+    use tauri::Manager;
+
+    if let Ok(mut guard) = state.process.lock() {
+        if let Some(mut proc_) = guard.take() {
+            stop_backend_process(&mut proc_);
+        }
+    }
+    let port = get_backend_port_from_env();
+    kill_process_on_port(port);
+    set_supervisor_phase(&app, SupervisedPhase::Restarting, None);
+
+    let config_path = get_config_path_from_app(&app);
+    match ensure_python_backend_running(Some(&app), config_path).await {
+        Ok(Some(proc_)) => {
+            let pid = proc_.child.id();
+            if let Ok(mut guard) = state.process.lock() {
+                *guard = Some(proc_);
+            }
+            set_supervisor_phase(&app, SupervisedPhase::Ready, Some(pid));
+            Ok("Python backend restarted".to_string())
+        }
+        Ok(None) => {
+            set_supervisor_phase(&app, SupervisedPhase::Ready, None);
+            Ok("Python backend already running".to_string())
+        }
+        Err(e) => {
+            set_supervisor_phase(&app, SupervisedPhase::Degraded, None);
+            set_supervisor_error(&app, e.clone());
+            Err(e)
+        }
+    }
+}
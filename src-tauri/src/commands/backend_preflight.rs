@@ -0,0 +1,263 @@
+//! Pre-flight check of the Python backend's dependencies, run before
+//! `ensure_python_backend_running` spawns the interpreter so a missing/mismatched package
+//! shows up as "missing: fastapi, torch>=2.1" instead of the backend silently failing to bind
+//! its port. Reuses `backend_host_command()` (the same cross-platform `python` invocation
+//! `backend_lifecycle` spawns the backend with) to run a short embedded script: it `ast`-parses
+//! the entry point(s) for top-level imports, maps each to a distribution name via
+//! `importlib.metadata.packages_distributions()`, and looks up whether that distribution is
+//! actually installed and, when a `requirements.txt` sits next to the entry point, whether the
+//! installed version satisfies its pinned constraint — all in the same interpreter/venv the
+//! backend itself would run in, rather than a Rust-side Python package registry that could
+//! drift from it.
+
+use std::path::PathBuf;
+use std::sync::Mutex;
+use std::time::SystemTime;
+
+use serde::{Deserialize, Serialize};
+
+use crate::backend_url::{find_backend_executable_path, find_main_py_path};
+use crate::commands::backend_lifecycle::backend_host_command;
+
+/// One import discovered in the entry point(s) and what the target interpreter knows about it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PackageCheck {
+    pub module: String,
+    pub distribution: String,
+    pub installed: bool,
+    pub version: Option<String>,
+    /// The constraint from `requirements.txt` (e.g. `">=2.1"`), if that distribution is pinned
+    /// there.
+    pub required: Option<String>,
+    /// True when `installed` is true but `version` doesn't satisfy `required`.
+    pub version_mismatch: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BackendEnvReport {
+    pub missing: Vec<PackageCheck>,
+    pub packages: Vec<PackageCheck>,
+}
+
+impl BackendEnvReport {
+    fn from_packages(packages: Vec<PackageCheck>) -> Self {
+        let missing = packages.iter().filter(|p| !p.installed || p.version_mismatch).cloned().collect();
+        BackendEnvReport { missing, packages }
+    }
+}
+
+/// Caches the last scan keyed by the entry point(s)' combined mtime, so repeated launches
+/// (restart_backend, the supervisor's restart path, a second `start_python_backend` call)
+/// skip re-parsing and re-shelling out to python when nothing on disk has changed.
+#[derive(Default)]
+pub struct BackendEnvCache {
+    last: Mutex<Option<(SystemTime, BackendEnvReport)>>,
+}
+
+/// `main.py` and, if present, `backend_gui_host.py` — the same entry points
+/// `ensure_python_backend_running` chooses between.
+fn entry_points() -> Vec<PathBuf> {
+    let mut entries = Vec::new();
+    if let Some(main_py) = find_main_py_path() {
+        if let Some(dir) = main_py.parent() {
+            let gui_host = dir.join("backend_gui_host.py");
+            if gui_host.exists() {
+                entries.push(gui_host);
+            }
+        }
+        entries.push(main_py);
+    }
+    entries
+}
+
+/// `requirements.txt` next to `main.py`, if present, for the version-constraint check.
+fn requirements_txt_path() -> Option<PathBuf> {
+    let path = find_main_py_path()?.parent()?.join("requirements.txt");
+    path.exists().then_some(path)
+}
+
+fn combined_mtime(paths: &[PathBuf]) -> Option<SystemTime> {
+    paths
+        .iter()
+        .filter_map(|p| std::fs::metadata(p).ok().and_then(|m| m.modified().ok()))
+        .max()
+}
+
+const SCAN_SCRIPT: &str = r#"
+import ast, json, re, sys
+
+try:
+    from importlib import metadata
+except ImportError:
+    import importlib_metadata as metadata
+
+ALIASES = {
+    "yaml": "pyyaml",
+    "cv2": "opencv-python",
+    "PIL": "pillow",
+    "sklearn": "scikit-learn",
+    "dotenv": "python-dotenv",
+    "jwt": "pyjwt",
+}
+
+REQUIREMENT_RE = re.compile(r"^([A-Za-z0-9_.\-]+)\s*(==|>=|<=|~=|>|<)?\s*([0-9][0-9A-Za-z.\-]*)?")
+
+def top_level_imports(path):
+    with open(path, "r", encoding="utf-8") as f:
+        tree = ast.parse(f.read(), filename=path)
+    names = set()
+    for node in ast.walk(tree):
+        if isinstance(node, ast.Import):
+            for alias in node.names:
+                names.add(alias.name.split(".")[0])
+        elif isinstance(node, ast.ImportFrom):
+            if node.level == 0 and node.module:
+                names.add(node.module.split(".")[0])
+    return names
+
+def parse_requirements(path):
+    constraints = {}
+    try:
+        with open(path, "r", encoding="utf-8") as f:
+            for line in f:
+                line = line.strip()
+                if not line or line.startswith("#"):
+                    continue
+                m = REQUIREMENT_RE.match(line)
+                if not m:
+                    continue
+                name, op, version = m.groups()
+                constraints[name.lower()] = (op, version)
+    except OSError:
+        pass
+    return constraints
+
+def version_tuple(version):
+    parts = []
+    for piece in re.split(r"[.\-]", version or ""):
+        m = re.match(r"\d+", piece)
+        parts.append(int(m.group()) if m else 0)
+    return tuple(parts)
+
+def satisfies(installed_version, op, required_version):
+    if not op or not required_version:
+        return True
+    installed, required = version_tuple(installed_version), version_tuple(required_version)
+    if op == "==":
+        return installed == required
+    if op == ">=":
+        return installed >= required
+    if op == "<=":
+        return installed <= required
+    if op == ">":
+        return installed > required
+    if op == "<":
+        return installed < required
+    if op == "~=":
+        return installed[: len(required) - 1] == required[: len(required) - 1] and installed >= required
+    return True
+
+requirements_path = sys.argv[1]
+constraints = parse_requirements(requirements_path) if requirements_path else {}
+
+stdlib = set(getattr(sys, "stdlib_module_names", ()))
+modules = set()
+for path in sys.argv[2:]:
+    try:
+        modules |= top_level_imports(path)
+    except Exception:
+        pass
+modules -= stdlib
+modules.discard("__future__")
+
+try:
+    dist_map = metadata.packages_distributions()
+except Exception:
+    dist_map = {}
+
+results = []
+for module in sorted(modules):
+    candidates = dist_map.get(module)
+    distribution = candidates[0] if candidates else ALIASES.get(module, module)
+    try:
+        version = metadata.version(distribution)
+        installed = True
+    except metadata.PackageNotFoundError:
+        installed = False
+        version = None
+    op, required_version = constraints.get(distribution.lower(), (None, None))
+    version_mismatch = installed and required_version is not None and not satisfies(version, op, required_version)
+    results.append({
+        "module": module,
+        "distribution": distribution,
+        "installed": installed,
+        "version": version,
+        "required": f"{op}{required_version}" if op and required_version else required_version,
+        "version_mismatch": version_mismatch,
+    })
+
+print(json.dumps(results))
+"#;
+
+async fn scan_entry_points(entries: &[PathBuf], requirements_path: Option<&PathBuf>) -> Result<Vec<PackageCheck>, String> {
+    let mut cmd = backend_host_command();
+    cmd.arg("-c").arg(SCAN_SCRIPT);
+    cmd.arg(requirements_path.map(|p| p.as_os_str()).unwrap_or_default());
+    for entry in entries {
+        cmd.arg(entry);
+    }
+    let output = tauri::async_runtime::spawn_blocking(move || cmd.output())
+        .await
+        .map_err(|e| format!("Failed to run dependency scan: {}", e))?
+        .map_err(|e| format!("Failed to launch python for dependency scan: {}", e))?;
+
+    if !output.status.success() {
+        return Err(format!(
+            "Dependency scan exited with {}: {}",
+            output.status,
+            String::from_utf8_lossy(&output.stderr)
+        ));
+    }
+    serde_json::from_slice::<Vec<PackageCheck>>(&output.stdout)
+        .map_err(|e| format!("Failed to parse dependency scan output: {}", e))
+}
+
+/// Parses the backend's entry point(s) for imports and checks each against the active
+/// interpreter's installed distributions, caching by the entry points' mtime so an unchanged
+/// backend source tree is only scanned once.
+#[tauri::command]
+pub async fn check_python_backend_env(
+    cache: tauri::State<'_, BackendEnvCache>,
+) -> Result<BackendEnvReport, String> {
+    if find_backend_executable_path().is_some() && find_main_py_path().is_none() {
+        // A packaged backend executable has no `.py` entry point to scan; report nothing
+        // missing rather than erroring on a dependency check that doesn't apply to it.
+        return Ok(BackendEnvReport::from_packages(Vec::new()));
+    }
+
+    let entries = entry_points();
+    if entries.is_empty() {
+        return Err("Python backend main.py not found".to_string());
+    }
+    let requirements_path = requirements_txt_path();
+    let mtime_inputs: Vec<PathBuf> = match &requirements_path {
+        Some(p) => entries.iter().cloned().chain(std::iter::once(p.clone())).collect(),
+        None => entries.clone(),
+    };
+    let mtime = combined_mtime(&mtime_inputs);
+
+    if let Some(mtime) = mtime {
+        if let Some((cached_mtime, report)) = cache.last.lock().unwrap().as_ref() {
+            if *cached_mtime == mtime {
+                return Ok(report.clone());
+            }
+        }
+    }
+
+    let packages = scan_entry_points(&entries, requirements_path.as_ref()).await?;
+    let report = BackendEnvReport::from_packages(packages);
+    if let Some(mtime) = mtime {
+        *cache.last.lock().unwrap() = Some((mtime, report.clone()));
+    }
+    Ok(report)
+}
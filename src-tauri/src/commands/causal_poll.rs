@@ -0,0 +1,144 @@
+// Causal-context (dotted version vector) long-poll for mount-point file/annotation changes,
+// so clients receive only what changed since their last-seen context instead of re-fetching
+// the whole list. The causal context is a JSON map `actor_id -> counter`; each update is
+// tagged with a "dot" `(actor, counter)`, and a record also carries the context of everything
+// it has already seen, which lets us tell a genuinely concurrent sibling edit apart from one
+// that's merely stale within the same response batch.
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::backend_url::get_backend_base_url;
+
+const LONG_POLL_TIMEOUT: Duration = Duration::from_secs(25);
+const RETRY_INTERVAL: Duration = Duration::from_secs(1);
+
+pub type CausalContext = HashMap<String, u64>;
+
+/// Tracks the latest causal context we've seen per mount point, so a client that starts
+/// without a `since` can still be handed a sensible baseline.
+#[derive(Default)]
+pub struct CausalPollState {
+    contexts: Mutex<HashMap<i32, CausalContext>>,
+}
+
+impl CausalPollState {
+    fn merged(&self, mp_id: i32, since: Option<CausalContext>) -> CausalContext {
+        let mut contexts = self.contexts.lock().unwrap();
+        let tracked = contexts.entry(mp_id).or_default();
+        if let Some(since) = since {
+            for (actor, counter) in since {
+                let entry = tracked.entry(actor).or_insert(0);
+                *entry = (*entry).max(counter);
+            }
+        }
+        tracked.clone()
+    }
+
+    fn update(&self, mp_id: i32, new_context: &CausalContext) {
+        let mut contexts = self.contexts.lock().unwrap();
+        let tracked = contexts.entry(mp_id).or_default();
+        for (actor, counter) in new_context {
+            let entry = tracked.entry(actor.clone()).or_insert(0);
+            *entry = (*entry).max(*counter);
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct ChangeRecord {
+    dot: (String, u64),
+    #[serde(default)]
+    context: CausalContext,
+    #[serde(flatten)]
+    rest: Value,
+}
+
+#[derive(Deserialize, Default)]
+struct ChangesResponse {
+    #[serde(default)]
+    changes: Vec<ChangeRecord>,
+    #[serde(default)]
+    context: CausalContext,
+}
+
+#[derive(Serialize)]
+pub struct PollResult {
+    changes: Vec<Value>,
+    context: CausalContext,
+}
+
+fn dominated_by_sibling(record: &ChangeRecord, others: &[ChangeRecord]) -> bool {
+    let (actor, counter) = &record.dot;
+    others.iter().any(|other| {
+        other.dot != record.dot
+            && other.context.get(actor).map(|seen| seen >= counter).unwrap_or(false)
+    })
+}
+
+/// Bounded long-poll: returns as soon as the backend reports changes, or after
+/// `LONG_POLL_TIMEOUT` with an unchanged context.
+#[tauri::command]
+pub async fn poll_mount_point_changes(
+    app: tauri::AppHandle,
+    state: tauri::State<'_, CausalPollState>,
+    mp_id: i32,
+    since: Option<CausalContext>,
+) -> Result<PollResult, String> {
+    let mut context = state.merged(mp_id, since);
+    let base_url = get_backend_base_url(&app);
+    let url = format!("{}/mount-points/{}/changes", base_url, mp_id);
+    let client = reqwest::Client::new();
+
+    let deadline = Instant::now() + LONG_POLL_TIMEOUT;
+    loop {
+        let resp = client
+            .request(Method::GET, &url)
+            .query(&[("since", serde_json::to_string(&context).unwrap_or_default())])
+            .send()
+            .await
+            .map_err(|e| format!("Backend request failed: {}", e))?;
+
+        if !resp.status().is_success() {
+            let body = resp.text().await.unwrap_or_default();
+            return Err(format!("Backend returned an error: {}", body));
+        }
+
+        let parsed: ChangesResponse = resp
+            .json()
+            .await
+            .map_err(|e| format!("Failed to parse changes response: {}", e))?;
+
+        if !parsed.changes.is_empty() {
+            let kept: Vec<&ChangeRecord> = parsed
+                .changes
+                .iter()
+                .filter(|r| !dominated_by_sibling(r, &parsed.changes))
+                .collect();
+
+            let mut new_context = parsed.context.clone();
+            for record in &kept {
+                let (actor, counter) = &record.dot;
+                let entry = new_context.entry(actor.clone()).or_insert(0);
+                *entry = (*entry).max(*counter);
+            }
+            state.update(mp_id, &new_context);
+
+            let changes: Vec<Value> = kept.into_iter().map(|r| r.rest.clone()).collect();
+            return Ok(PollResult {
+                changes,
+                context: new_context,
+            });
+        }
+
+        if Instant::now() >= deadline {
+            return Ok(PollResult { changes: vec![], context });
+        }
+        tokio::time::sleep(RETRY_INTERVAL).await;
+        context = state.merged(mp_id, None);
+    }
+}
@@ -1,24 +1,216 @@
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+
 use reqwest::Method;
+use serde::Serialize;
+use tauri::{Emitter, Manager};
 
+use crate::backend_url::get_backend_base_url;
+use crate::commands::features::load_runtime_features;
 use crate::commands::http;
+use crate::commands::secrets;
 
-#[tauri::command]
-pub async fn chat_query(
-    app: tauri::AppHandle,
-    query: String,
+/// Tracks in-flight streamed chat requests so `chat_stream_abort` can cancel one by id
+/// without tearing down the others.
+#[derive(Default)]
+pub struct ChatStreamRegistry {
+    cancel_flags: Mutex<HashMap<String, Arc<AtomicBool>>>,
+}
+
+impl ChatStreamRegistry {
+    pub(crate) fn register(&self, request_id: &str) -> Arc<AtomicBool> {
+        let flag = Arc::new(AtomicBool::new(false));
+        self.cancel_flags
+            .lock()
+            .unwrap()
+            .insert(request_id.to_string(), flag.clone());
+        flag
+    }
+
+    pub(crate) fn forget(&self, request_id: &str) {
+        self.cancel_flags.lock().unwrap().remove(request_id);
+    }
+}
+
+#[derive(Serialize, Clone)]
+struct ChatStreamDelta<'a> {
+    request_id: &'a str,
+    delta: &'a str,
+}
+
+#[derive(Serialize, Clone)]
+struct ChatStreamDone<'a> {
+    request_id: &'a str,
+    text: &'a str,
+    usage: Option<serde_json::Value>,
+    error: Option<String>,
+}
+
+fn build_chat_payload(
+    app: &tauri::AppHandle,
+    query: &str,
     api_key: Option<String>,
     model: Option<String>,
     base_url: Option<String>,
     platform: Option<String>,
-) -> Result<String, String> {
+    use_vector_store: bool,
+) -> serde_json::Value {
+    let resolved_api_key = api_key.or_else(|| platform.as_deref().and_then(|p| secrets::resolve_api_key(app, p)));
     let mut payload = serde_json::json!({
         "query": query,
-        "api_key": api_key.unwrap_or_default(),
+        "api_key": resolved_api_key.unwrap_or_default(),
         "model": model.unwrap_or("deepseek-chat".to_string()),
         "base_url": base_url.unwrap_or_default()
     });
     if let Some(p) = platform {
         payload["platform"] = serde_json::json!(p);
     }
+    // Experimental: routes retrieval through the vector store instead of the default
+    // keyword/weight lookup while the feature is still behind a flag.
+    if use_vector_store {
+        payload["use_vector_store"] = serde_json::json!(true);
+    }
+    payload
+}
+
+#[tauri::command]
+pub async fn chat_query(
+    app: tauri::AppHandle,
+    query: String,
+    api_key: Option<String>,
+    model: Option<String>,
+    base_url: Option<String>,
+    platform: Option<String>,
+) -> Result<String, String> {
+    let use_vector_store = load_runtime_features(&app).vector_store;
+    let payload = build_chat_payload(&app, &query, api_key, model, base_url, platform, use_vector_store);
     http::backend_json(&app, Method::POST, "/chat/query", None, Some(payload)).await
 }
+
+/// Streaming variant of `chat_query`. Reads the backend response incrementally and emits
+/// a `chat-stream-delta` event per chunk, followed by a terminal `chat-stream-done` event
+/// carrying the full concatenated text and usage metadata, both keyed by `request_id` so
+/// the frontend can demux concurrent requests.
+#[tauri::command]
+pub async fn chat_query_stream(
+    app: tauri::AppHandle,
+    request_id: String,
+    query: String,
+    api_key: Option<String>,
+    model: Option<String>,
+    base_url: Option<String>,
+    platform: Option<String>,
+) -> Result<(), String> {
+    let use_vector_store = load_runtime_features(&app).vector_store;
+    let mut payload = build_chat_payload(&app, &query, api_key, model, base_url, platform, use_vector_store);
+    payload["stream"] = serde_json::json!(true);
+
+    let registry = app.state::<ChatStreamRegistry>();
+    let cancel_flag = registry.register(&request_id);
+
+    let backend_base = get_backend_base_url(&app);
+    let url = format!("{}/chat/query", backend_base);
+    let client = reqwest::Client::new();
+
+    let result = async {
+        let mut resp = client
+            .post(&url)
+            .json(&payload)
+            .send()
+            .await
+            .map_err(|e| format!("Failed to reach backend: {}", e))?;
+
+        if !resp.status().is_success() {
+            let body = resp.text().await.unwrap_or_default();
+            return Err(format!("Backend returned an error: {}", body));
+        }
+
+        let mut full_text = String::new();
+        let mut usage: Option<serde_json::Value> = None;
+        let mut carry = String::new();
+
+        while let Some(chunk) = resp
+            .chunk()
+            .await
+            .map_err(|e| format!("Stream read failed: {}", e))?
+        {
+            if cancel_flag.load(Ordering::Relaxed) {
+                break;
+            }
+            carry.push_str(&String::from_utf8_lossy(&chunk));
+
+            while let Some(pos) = carry.find('\n') {
+                let line = carry[..pos].trim().to_string();
+                carry.drain(..=pos);
+
+                let Some(data) = line.strip_prefix("data:") else {
+                    continue;
+                };
+                let data = data.trim();
+                if data.is_empty() || data == "[DONE]" {
+                    continue;
+                }
+                let Ok(parsed) = serde_json::from_str::<serde_json::Value>(data) else {
+                    continue;
+                };
+                if let Some(u) = parsed.get("usage") {
+                    usage = Some(u.clone());
+                }
+                if let Some(delta) = parsed.get("delta").and_then(|d| d.as_str()) {
+                    full_text.push_str(delta);
+                    let _ = app.emit(
+                        "chat-stream-delta",
+                        ChatStreamDelta {
+                            request_id: &request_id,
+                            delta,
+                        },
+                    );
+                }
+            }
+        }
+
+        Ok((full_text, usage))
+    }
+    .await;
+
+    registry.forget(&request_id);
+
+    match result {
+        Ok((text, usage)) => {
+            let _ = app.emit(
+                "chat-stream-done",
+                ChatStreamDone {
+                    request_id: &request_id,
+                    text: &text,
+                    usage,
+                    error: None,
+                },
+            );
+            Ok(())
+        }
+        Err(e) => {
+            let _ = app.emit(
+                "chat-stream-done",
+                ChatStreamDone {
+                    request_id: &request_id,
+                    text: "",
+                    usage: None,
+                    error: Some(e.clone()),
+                },
+            );
+            Err(e)
+        }
+    }
+}
+
+/// Cancels an in-flight `chat_query_stream` call by request id. The stream loop checks the
+/// flag between chunks and emits its terminal `chat-stream-done` event as usual once it stops.
+#[tauri::command]
+pub fn chat_stream_abort(app: tauri::AppHandle, request_id: String) -> Result<(), String> {
+    let registry = app.state::<ChatStreamRegistry>();
+    if let Some(flag) = registry.cancel_flags.lock().unwrap().get(&request_id) {
+        flag.store(true, Ordering::Relaxed);
+    }
+    Ok(())
+}
@@ -1,6 +1,23 @@
 use reqwest::Method;
+use serde::{Deserialize, Serialize};
 
-use crate::commands::http;
+use crate::commands::http::{self, BioError};
+use crate::commands::typed_client::backend_json_typed;
+
+/// A single entry in a move batch: `id` is the document or directory id, `target_id` is
+/// the destination directory (or `None` to move to the root).
+#[derive(Deserialize)]
+pub struct MoveBatchItem {
+    id: i32,
+    target_id: Option<i32>,
+}
+
+fn to_move_payload(items: Vec<MoveBatchItem>) -> Vec<serde_json::Value> {
+    items
+        .into_iter()
+        .map(|item| serde_json::json!({ "id": item.id, "target_id": item.target_id }))
+        .collect()
+}
 
 #[tauri::command]
 pub async fn get_directories(app: tauri::AppHandle) -> Result<String, String> {
@@ -25,6 +42,17 @@ pub async fn move_document(app: tauri::AppHandle, document_id: i32, directory_id
     http::backend_json(&app, Method::PUT, &path, None, Some(payload)).await
 }
 
+/// Batch variant of `move_document`: moves many documents to (possibly different)
+/// directories in one request instead of one `/move` call per document.
+#[tauri::command]
+pub async fn move_documents_batch(
+    app: tauri::AppHandle,
+    items: Vec<MoveBatchItem>,
+) -> Result<String, String> {
+    let payload = serde_json::json!({ "items": to_move_payload(items) });
+    http::backend_json(&app, Method::PUT, "/documents/move/batch", None, Some(payload)).await
+}
+
 #[tauri::command]
 pub async fn move_directory(app: tauri::AppHandle, directory_id: i32, parent_id: Option<i32>) -> Result<String, String> {
     let payload = serde_json::json!({
@@ -34,6 +62,99 @@ pub async fn move_directory(app: tauri::AppHandle, directory_id: i32, parent_id:
     http::backend_json(&app, Method::PUT, &path, None, Some(payload)).await
 }
 
+/// Batch variant of `move_directory`.
+#[tauri::command]
+pub async fn move_directories_batch(
+    app: tauri::AppHandle,
+    items: Vec<MoveBatchItem>,
+) -> Result<String, String> {
+    let payload = serde_json::json!({ "items": to_move_payload(items) });
+    http::backend_json(&app, Method::PUT, "/directories/move/batch", None, Some(payload)).await
+}
+
+/// How to resolve a file/document that exists at both the source and destination path when
+/// merging a directory move into an existing directory. `ManualResolution` moves nothing for
+/// the colliding entries and reports them in `MovePlan::conflicts` instead of guessing.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MergePolicy {
+    KeepSource,
+    KeepTarget,
+    KeepBothRename,
+    ManualResolution,
+}
+
+/// One collision discovered while planning a merge: the same relative path exists under both
+/// the moved directory and the destination directory.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MoveConflict {
+    pub relative_path: String,
+    pub source_id: i32,
+    pub target_id: i32,
+}
+
+/// A file or directory found under the source tree that the backend has no record of (not a
+/// tracked document or directory row) — reported rather than moved or deleted so the caller
+/// can decide what to do with it instead of it silently disappearing.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UntrackedEntry {
+    pub relative_path: String,
+}
+
+/// One step the backend would take (or took) to carry out the merge, e.g. `{"op": "move",
+/// "id": 12, "target_id": 7}` — left as an opaque value since its shape mirrors whatever the
+/// backend's own merge planner emits.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MoveAction {
+    #[serde(flatten)]
+    pub detail: serde_json::Value,
+}
+
+/// Result of `move_directory_with_policy`: in dry-run mode this is the plan only (nothing
+/// written yet); otherwise it's a record of what was actually done, so the two modes share one
+/// response shape and the frontend can render a preview and the final result the same way.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MovePlan {
+    pub actions: Vec<MoveAction>,
+    pub conflicts: Vec<MoveConflict>,
+    pub untracked: Vec<UntrackedEntry>,
+    pub applied: bool,
+}
+
+/// Merge-aware variant of `move_directory`: when `target_parent_id` already contains a
+/// directory with the same name, recursively merges the two instead of erroring, resolving
+/// any file/document collisions per `policy`. With `dry_run: true` nothing is written — the
+/// backend only returns the `MovePlan` it would have executed, so the caller can surface
+/// conflicts and let the user confirm before anything moves.
+#[tauri::command]
+pub async fn move_directory_with_policy(
+    app: tauri::AppHandle,
+    directory_id: i32,
+    target_parent_id: Option<i32>,
+    policy: MergePolicy,
+    dry_run: bool,
+) -> Result<MovePlan, BioError> {
+    #[derive(Serialize)]
+    struct Body {
+        target_parent_id: Option<i32>,
+        policy: MergePolicy,
+        dry_run: bool,
+    }
+    let path = format!("/directories/{}/move-merge", directory_id);
+    backend_json_typed::<Body, MovePlan>(
+        &app,
+        Method::POST,
+        &path,
+        None,
+        Some(&Body {
+            target_parent_id,
+            policy,
+            dry_run,
+        }),
+    )
+    .await
+}
+
 #[tauri::command]
 pub async fn delete_directory(app: tauri::AppHandle, directory_id: i32) -> Result<String, String> {
     let path = format!("/directories/{}", directory_id);
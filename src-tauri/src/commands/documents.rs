@@ -1,195 +1,111 @@
-use std::process::Command;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use reqwest::Method;
+use serde::Serialize;
+use tauri::Emitter;
+
 use crate::backend_url::get_backend_base_url;
+use crate::commands::http;
+
+#[derive(Serialize, Clone)]
+struct IngestProgress<'a> {
+    upload_id: &'a str,
+    document_id: Option<i32>,
+    phase: &'a str,
+    done: bool,
+    error: Option<String>,
+}
 
+/// Uploads the file via the resumable chunked path, then — once the backend has a
+/// `document_id` — tails `/documents/{id}/ingest-events`, a newline-delimited JSON event
+/// stream, emitting a `document-ingest-progress` event per phase (parsing, summarizing,
+/// knowledge-point generation) until a terminal `{"done": true}` line or the connection
+/// closes, so the UI sees granular progress instead of one opaque success/failure.
 #[tauri::command]
 pub async fn upload_document(app: tauri::AppHandle, file_path: String) -> Result<String, String> {
-    let base_url = get_backend_base_url(&app);
-    let python_script = format!(
-        r#"
-import sys
-import requests
-import json
-import os
-
-file_path = r"{}"
-base_url = "{}"
-
-if not os.path.exists(file_path):
-    result = {{
-        "success": False,
-        "data": None,
-        "error": "File not found"
-    }}
-    print(json.dumps(result))
-    sys.exit(1)
-
-try:
-    with open(file_path, 'rb') as f:
-        files = {{'file': (os.path.basename(file_path), f, 'application/octet-stream')}}
-        response = requests.post(base_url + '/documents/upload', files=files)
-    
-    result = {{
-        "success": response.status_code == 200,
-        "data": response.json() if response.status_code == 200 else None,
-        "error": None if response.status_code == 200 else response.text
-    }}
-    print(json.dumps(result))
-except Exception as e:
-    result = {{
-        "success": False,
-        "data": None,
-        "error": str(e)
-    }}
-    print(json.dumps(result))
-"#,
-        file_path.replace('\\', "\\\\"),
-        base_url.replace('\\', "\\\\").replace('"', "\\\"")
-    );
-
-    let output = Command::new("python")
-        .arg("-c")
-        .arg(&python_script)
-        .output()
-        .map_err(|e| format!("Failed to execute Python: {}", e))?;
-
-    let output_str = String::from_utf8_lossy(&output.stdout);
-    Ok(output_str.to_string())
+    let ts = SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_millis()).unwrap_or(0);
+    let upload_id = format!("doc-upload-{}-{}", std::process::id(), ts);
+    let result = http::backend_upload_file(&app, "/documents/upload", &file_path, &upload_id).await?;
+
+    let document_id = serde_json::from_str::<serde_json::Value>(&result)
+        .ok()
+        .and_then(|v| v.get("data").and_then(|d| d.get("document_id").and_then(|id| id.as_i64())))
+        .map(|id| id as i32);
+
+    if let Some(document_id) = document_id {
+        follow_ingestion(&app, &upload_id, document_id).await;
+    }
+
+    Ok(result)
+}
+
+async fn follow_ingestion(app: &tauri::AppHandle, upload_id: &str, document_id: i32) {
+    let base_url = get_backend_base_url(app);
+    let url = format!("{}/documents/{}/ingest-events", base_url, document_id);
+    let client = reqwest::Client::new();
+
+    let mut resp = match client.get(&url).send().await {
+        Ok(r) if r.status().is_success() => r,
+        _ => return,
+    };
+
+    let mut carry = String::new();
+
+    loop {
+        let chunk = match resp.chunk().await {
+            Ok(Some(c)) => c,
+            _ => break,
+        };
+        carry.push_str(&String::from_utf8_lossy(&chunk));
+
+        while let Some(pos) = carry.find('\n') {
+            let line = carry[..pos].trim().to_string();
+            carry.drain(..=pos);
+            if line.is_empty() {
+                continue;
+            }
+            let Ok(event) = serde_json::from_str::<serde_json::Value>(&line) else {
+                continue;
+            };
+            let phase = event.get("phase").and_then(|p| p.as_str()).unwrap_or("unknown").to_string();
+            let done = event.get("done").and_then(|d| d.as_bool()).unwrap_or(false);
+            let error = event.get("error").and_then(|e| e.as_str()).map(str::to_string);
+            let _ = app.emit(
+                "document-ingest-progress",
+                IngestProgress {
+                    upload_id,
+                    document_id: Some(document_id),
+                    phase: &phase,
+                    done,
+                    error,
+                },
+            );
+            if done {
+                return;
+            }
+        }
+    }
 }
 
 #[tauri::command]
 pub async fn get_documents(app: tauri::AppHandle) -> Result<String, String> {
-    let base_url = get_backend_base_url(&app);
-    let base_escaped = base_url.replace('\\', "\\\\").replace('"', "\\\"");
-    let python_script = format!(
-        r#"
-import sys
-import requests
-import json
-
-base_url = "{}"
-try:
-    response = requests.get(base_url + '/documents')
-    result = {{
-        "success": response.status_code == 200,
-        "data": response.json() if response.status_code == 200 else None,
-        "error": None if response.status_code == 200 else response.text
-    }}
-    print(json.dumps(result))
-except Exception as e:
-    result = {{
-        "success": False,
-        "data": None,
-        "error": str(e)
-    }}
-    print(json.dumps(result))
-"#,
-        base_escaped
-    );
-
-    let output = Command::new("python")
-        .arg("-c")
-        .arg(&python_script)
-        .output()
-        .map_err(|e| format!("Failed to execute Python: {}", e))?;
-
-    let output_str = String::from_utf8_lossy(&output.stdout);
-    Ok(output_str.to_string())
+    http::backend_json(&app, Method::GET, "/documents", None, None).await
 }
 
 #[tauri::command]
 pub async fn delete_document(app: tauri::AppHandle, document_id: i32) -> Result<String, String> {
-    let base_url = get_backend_base_url(&app);
-    let base_escaped = base_url.replace('\\', "\\\\").replace('"', "\\\"");
-    let python_script = format!(
-        r#"
-import sys
-import requests
-import json
-
-base_url = "{}"
-document_id = {}
-
-try:
-    response = requests.delete(base_url + '/documents/{{}}'.format(document_id))
-    result = {{
-        "success": response.status_code == 200,
-        "data": response.json() if response.status_code == 200 else None,
-        "error": None if response.status_code == 200 else response.text
-    }}
-    print(json.dumps(result))
-except Exception as e:
-    result = {{
-        "success": False,
-        "data": None,
-        "error": str(e)
-    }}
-    print(json.dumps(result))
-"#,
-        base_escaped,
-        document_id
-    );
-
-    let output = Command::new("python")
-        .arg("-c")
-        .arg(&python_script)
-        .output()
-        .map_err(|e| format!("Failed to execute Python: {}", e))?;
-
-    let output_str = String::from_utf8_lossy(&output.stdout);
-    Ok(output_str.to_string())
+    let path = format!("/documents/{}", document_id);
+    http::backend_json(&app, Method::DELETE, &path, None, None).await
 }
 
 #[tauri::command]
 pub async fn get_document_summary_by_id(app: tauri::AppHandle, document_id: i32) -> Result<String, String> {
-    let base_url = get_backend_base_url(&app);
-    let base_escaped = base_url.replace('\\', "\\\\").replace('"', "\\\"");
-    let python_script = format!(
-        r#"
-import sys
-import requests
-import json
-base_url = "{}"
-try:
-    r = requests.get(base_url + '/documents/{}/summary')
-    out = {{"success": r.status_code == 200, "data": r.json() if r.status_code == 200 else None, "error": None if r.status_code == 200 else r.text}}
-    print(json.dumps(out))
-except Exception as e:
-    print(json.dumps({{"success": False, "data": None, "error": str(e)}}))
-"#,
-        base_escaped,
-        document_id
-    );
-    let output = Command::new("python").arg("-c").arg(&python_script).output()
-        .map_err(|e| format!("Failed to execute Python: {}", e))?;
-    Ok(String::from_utf8_lossy(&output.stdout).to_string())
+    let path = format!("/documents/{}/summary", document_id);
+    http::backend_json(&app, Method::GET, &path, None, None).await
 }
 
 #[tauri::command]
 pub async fn get_document_preview_by_id(app: tauri::AppHandle, document_id: i32) -> Result<String, String> {
-    let base_url = get_backend_base_url(&app);
-    let base_escaped = base_url.replace('\\', "\\\\").replace('"', "\\\"");
-    let python_script = format!(
-        r#"
-import sys
-import requests
-import json
-import base64
-base_url = "{}"
-try:
-    r = requests.get(base_url + '/documents/{}/preview')
-    if r.status_code != 200:
-        out = {{"success": False, "data": None, "version": "", "error": r.text}}
-    else:
-        ver = r.headers.get("X-Preview-Version") or r.headers.get("x-preview-version") or ""
-        out = {{"success": True, "data": base64.b64encode(r.content).decode(), "version": ver if isinstance(ver, str) else "", "error": None}}
-    print(json.dumps(out))
-except Exception as e:
-    print(json.dumps({{"success": False, "data": None, "error": str(e)}}))
-"#,
-        base_escaped,
-        document_id
-    );
-    let output = Command::new("python").arg("-c").arg(&python_script).output()
-        .map_err(|e| format!("Failed to execute Python: {}", e))?;
-    Ok(String::from_utf8_lossy(&output.stdout).to_string())
+    let path = format!("/documents/{}/preview", document_id);
+    http::backend_binary_with_version(&app, &path, vec![]).await
 }
@@ -1,22 +1,163 @@
+use std::sync::atomic::Ordering;
+
 use reqwest::Method;
+use serde::Serialize;
+use tauri::{Emitter, Manager};
 
+use crate::backend_url::get_backend_base_url;
+use crate::commands::chat::ChatStreamRegistry;
+use crate::commands::features::load_runtime_features;
 use crate::commands::http;
+use crate::commands::secrets;
 
-#[tauri::command]
-pub async fn evaluation_generate(
-    app: tauri::AppHandle,
-    prompt: String,
-    template: String,
+/// Resolves the key to send to the backend: an explicit `api_key` always wins (kept for
+/// backward compatibility with callers that already hold one), otherwise falls back to
+/// whatever is stored in the encrypted secrets store for `platform` so the frontend doesn't
+/// have to hold or re-send the raw key on every call.
+fn resolve_evaluation_api_key(app: &tauri::AppHandle, api_key: Option<String>, platform: Option<&str>) -> Option<String> {
+    api_key.or_else(|| platform.and_then(|p| secrets::resolve_api_key(app, p)))
+}
+
+fn build_evaluation_payload(
+    app: &tauri::AppHandle,
+    prompt: &str,
+    template: &str,
     api_key: Option<String>,
     platform: Option<String>,
-) -> Result<String, String> {
+    use_vector_store: bool,
+) -> serde_json::Value {
+    let resolved_api_key = resolve_evaluation_api_key(app, api_key, platform.as_deref());
     let mut payload = serde_json::json!({
         "prompt": prompt,
         "template": template,
-        "api_key": api_key.unwrap_or_default()
+        "api_key": resolved_api_key.unwrap_or_default()
     });
     if let Some(p) = platform {
         payload["platform"] = serde_json::json!(p);
     }
+    // Experimental: shares the `vector_store` flag with `chat::build_chat_payload` since both
+    // are generation endpoints backed by the same retrieval path.
+    if use_vector_store {
+        payload["use_vector_store"] = serde_json::json!(true);
+    }
+    payload
+}
+
+#[tauri::command]
+pub async fn evaluation_generate(
+    app: tauri::AppHandle,
+    prompt: String,
+    template: String,
+    api_key: Option<String>,
+    platform: Option<String>,
+) -> Result<String, String> {
+    let use_vector_store = load_runtime_features(&app).vector_store;
+    let payload = build_evaluation_payload(&app, &prompt, &template, api_key, platform, use_vector_store);
     http::backend_json(&app, Method::POST, "/evaluation/generate", None, Some(payload)).await
 }
+
+#[derive(Serialize, Clone)]
+struct EvaluationStreamDelta<'a> {
+    request_id: &'a str,
+    delta: &'a str,
+}
+
+#[derive(Serialize, Clone)]
+struct EvaluationStreamDone<'a> {
+    request_id: &'a str,
+    text: &'a str,
+    error: Option<String>,
+}
+
+/// Streaming variant of `evaluation_generate`, mirroring `chat::chat_query_stream`: opens an
+/// SSE connection to the backend and forwards each `data:` delta to the frontend as it's
+/// produced instead of blocking until generation finishes. Shares `ChatStreamRegistry` with
+/// the chat streams since both are just "cancel an in-flight request by id".
+#[tauri::command]
+pub async fn evaluation_generate_stream(
+    app: tauri::AppHandle,
+    request_id: String,
+    prompt: String,
+    template: String,
+    api_key: Option<String>,
+    platform: Option<String>,
+) -> Result<(), String> {
+    let use_vector_store = load_runtime_features(&app).vector_store;
+    let mut payload = build_evaluation_payload(&app, &prompt, &template, api_key, platform, use_vector_store);
+    payload["stream"] = serde_json::json!(true);
+
+    let registry = app.state::<ChatStreamRegistry>();
+    let cancel_flag = registry.register(&request_id);
+
+    let backend_base = get_backend_base_url(&app);
+    let url = format!("{}/evaluation/generate", backend_base);
+    let client = reqwest::Client::new();
+
+    let result = async {
+        let mut resp = client
+            .post(&url)
+            .json(&payload)
+            .send()
+            .await
+            .map_err(|e| format!("Failed to reach backend: {}", e))?;
+
+        if !resp.status().is_success() {
+            let body = resp.text().await.unwrap_or_default();
+            return Err(format!("Backend returned an error: {}", body));
+        }
+
+        let mut full_text = String::new();
+        let mut carry = String::new();
+        while let Some(chunk) = resp.chunk().await.map_err(|e| format!("Stream read failed: {}", e))? {
+            if cancel_flag.load(Ordering::Relaxed) {
+                break;
+            }
+            carry.push_str(&String::from_utf8_lossy(&chunk));
+
+            while let Some(pos) = carry.find('\n') {
+                let line = carry[..pos].trim().to_string();
+                carry.drain(..=pos);
+
+                let Some(data) = line.strip_prefix("data:") else {
+                    continue;
+                };
+                let data = data.trim();
+                if data.is_empty() || data == "[DONE]" {
+                    continue;
+                }
+                let Ok(parsed) = serde_json::from_str::<serde_json::Value>(data) else {
+                    continue;
+                };
+                if let Some(delta) = parsed.get("delta").and_then(|d| d.as_str()) {
+                    full_text.push_str(delta);
+                    let _ = app.emit(
+                        "evaluation-stream-delta",
+                        EvaluationStreamDelta { request_id: &request_id, delta },
+                    );
+                }
+            }
+        }
+
+        Ok(full_text)
+    }
+    .await;
+
+    registry.forget(&request_id);
+
+    match result {
+        Ok(text) => {
+            let _ = app.emit(
+                "evaluation-stream-done",
+                EvaluationStreamDone { request_id: &request_id, text: &text, error: None },
+            );
+            Ok(())
+        }
+        Err(e) => {
+            let _ = app.emit(
+                "evaluation-stream-done",
+                EvaluationStreamDone { request_id: &request_id, text: "", error: Some(e.clone()) },
+            );
+            Err(e)
+        }
+    }
+}
@@ -0,0 +1,77 @@
+// Runtime-togglable experimental feature flags, persisted alongside the storage config in
+// the shared app config file so a flag can be flipped without rebuilding the app.
+use std::fs;
+
+use serde::{Deserialize, Serialize};
+
+use crate::backend_url::get_config_path_from_app;
+
+const FEATURES_CONFIG_KEY: &str = "runtimeFeatures";
+
+/// Every field defaults off and has `#[serde(default)]`, so adding a new flag here never
+/// breaks an existing config file that predates it.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+pub struct RuntimeFeatures {
+    #[serde(default)]
+    pub vector_store: bool,
+    #[serde(default)]
+    pub graph_embeddings: bool,
+    #[serde(default)]
+    pub local_ocr: bool,
+}
+
+pub fn load_runtime_features(app: &tauri::AppHandle) -> RuntimeFeatures {
+    let Some(config_path) = get_config_path_from_app(app) else {
+        return RuntimeFeatures::default();
+    };
+    let Ok(contents) = fs::read_to_string(&config_path) else {
+        return RuntimeFeatures::default();
+    };
+    let Ok(config) = serde_json::from_str::<serde_json::Value>(&contents) else {
+        return RuntimeFeatures::default();
+    };
+    config
+        .get(FEATURES_CONFIG_KEY)
+        .and_then(|v| serde_json::from_value(v.clone()).ok())
+        .unwrap_or_default()
+}
+
+fn save_runtime_features(app: &tauri::AppHandle, features: &RuntimeFeatures) -> Result<(), String> {
+    let config_path = get_config_path_from_app(app).ok_or("Config path not found")?;
+    let mut config = if config_path.exists() {
+        let raw = fs::read_to_string(&config_path).map_err(|e| e.to_string())?;
+        serde_json::from_str::<serde_json::Value>(&raw).unwrap_or_else(|_| serde_json::json!({}))
+    } else {
+        serde_json::json!({})
+    };
+    if !config.is_object() {
+        config = serde_json::json!({});
+    }
+    if let Some(obj) = config.as_object_mut() {
+        obj.insert(
+            FEATURES_CONFIG_KEY.to_string(),
+            serde_json::to_value(features).unwrap_or_default(),
+        );
+    }
+    let parent = config_path.parent().ok_or("Invalid config path")?;
+    fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+    fs::write(&config_path, config.to_string()).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub fn get_runtime_features(app: tauri::AppHandle) -> Result<RuntimeFeatures, String> {
+    Ok(load_runtime_features(&app))
+}
+
+#[tauri::command]
+pub fn set_runtime_feature(app: tauri::AppHandle, name: String, enabled: bool) -> Result<RuntimeFeatures, String> {
+    let mut features = load_runtime_features(&app);
+    match name.as_str() {
+        "vector_store" => features.vector_store = enabled,
+        "graph_embeddings" => features.graph_embeddings = enabled,
+        "local_ocr" => features.local_ocr = enabled,
+        _ => return Err(format!("Unknown feature flag: {}", name)),
+    }
+    save_runtime_features(&app, &features)?;
+    Ok(features)
+}
@@ -1,7 +1,216 @@
-use std::fs;
-use std::process::Command;
-use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+use tauri::{Emitter, Manager};
+use tokio::sync::Semaphore;
+
 use crate::backend_url::get_backend_base_url;
+use crate::commands::{http, models, typed_client};
+
+/// Tracks in-flight `follow_job_logs` streams so `stop_following_job` can cancel one by
+/// job id, mirroring `chat::ChatStreamRegistry`.
+#[derive(Default)]
+pub struct JobLogStreamRegistry {
+    cancel_flags: Mutex<HashMap<String, Arc<AtomicBool>>>,
+}
+
+impl JobLogStreamRegistry {
+    fn register(&self, job_id: &str) -> Arc<AtomicBool> {
+        let flag = Arc::new(AtomicBool::new(false));
+        self.cancel_flags.lock().unwrap().insert(job_id.to_string(), flag.clone());
+        flag
+    }
+
+    fn forget(&self, job_id: &str) {
+        self.cancel_flags.lock().unwrap().remove(job_id);
+    }
+}
+
+#[derive(Serialize, Clone)]
+struct JobLogLine<'a> {
+    job_id: &'a str,
+    line: &'a str,
+}
+
+#[derive(Serialize, Clone)]
+struct JobStatusUpdate<'a> {
+    job_id: &'a str,
+    status: &'a str,
+}
+
+const TERMINAL_JOB_STATUSES: &[&str] = &["succeeded", "failed", "cancelled"];
+
+const JOBS_FILENAME: &str = "finetuning_jobs.json";
+const POLL_INTERVAL: Duration = Duration::from_secs(5);
+const MAX_CONCURRENT_POLLS: usize = 4;
+
+#[derive(Clone, Serialize, Deserialize)]
+struct TrackedJob {
+    id: String,
+    status: String,
+    paused: bool,
+}
+
+/// Tauri-managed registry of submitted finetuning jobs, persisted to disk so the poller
+/// can resume tracking them after an app restart.
+#[derive(Default)]
+pub struct FinetuningRegistry {
+    jobs: Mutex<HashMap<String, TrackedJob>>,
+}
+
+impl FinetuningRegistry {
+    fn jobs_store_path(app: &tauri::AppHandle) -> Option<PathBuf> {
+        app.path().app_data_dir().ok().map(|d| d.join(JOBS_FILENAME))
+    }
+
+    fn load(&self, app: &tauri::AppHandle) {
+        let Some(path) = Self::jobs_store_path(app) else { return };
+        let Ok(raw) = std::fs::read_to_string(&path) else { return };
+        let Ok(jobs) = serde_json::from_str::<HashMap<String, TrackedJob>>(&raw) else { return };
+        *self.jobs.lock().unwrap() = jobs;
+    }
+
+    fn persist(&self, app: &tauri::AppHandle) {
+        let Some(path) = Self::jobs_store_path(app) else { return };
+        if let Some(parent) = path.parent() {
+            let _ = std::fs::create_dir_all(parent);
+        }
+        if let Ok(raw) = serde_json::to_string(&*self.jobs.lock().unwrap()) {
+            let _ = std::fs::write(&path, raw);
+        }
+    }
+
+    fn track(&self, app: &tauri::AppHandle, id: &str) {
+        self.jobs.lock().unwrap().insert(
+            id.to_string(),
+            TrackedJob {
+                id: id.to_string(),
+                status: "submitted".to_string(),
+                paused: false,
+            },
+        );
+        self.persist(app);
+    }
+
+    fn set_status(&self, app: &tauri::AppHandle, id: &str, status: &str) {
+        if let Some(job) = self.jobs.lock().unwrap().get_mut(id) {
+            job.status = status.to_string();
+        }
+        self.persist(app);
+    }
+
+    fn set_paused(&self, app: &tauri::AppHandle, id: &str, paused: bool) {
+        if let Some(job) = self.jobs.lock().unwrap().get_mut(id) {
+            job.paused = paused;
+        }
+        self.persist(app);
+    }
+
+    fn forget(&self, app: &tauri::AppHandle, id: &str) {
+        self.jobs.lock().unwrap().remove(id);
+        self.persist(app);
+    }
+
+    fn pollable_ids(&self) -> Vec<String> {
+        self.jobs
+            .lock()
+            .unwrap()
+            .values()
+            .filter(|j| !j.paused)
+            .map(|j| j.id.clone())
+            .collect()
+    }
+}
+
+#[derive(Serialize, Clone)]
+struct JobStatusEvent<'a> {
+    job_id: &'a str,
+    status: serde_json::Value,
+}
+
+#[derive(Serialize, Clone)]
+struct JobLogEvent<'a> {
+    job_id: &'a str,
+    logs: serde_json::Value,
+}
+
+/// Single background poller spawned once at startup. Polls status and logs for every
+/// tracked, non-paused job on a fixed interval, bounding concurrent backend requests with
+/// a semaphore so a large job list doesn't open dozens of connections at once.
+pub async fn run_job_poller(app: tauri::AppHandle) {
+    app.state::<FinetuningRegistry>().load(&app);
+    let semaphore = std::sync::Arc::new(Semaphore::new(MAX_CONCURRENT_POLLS));
+    loop {
+        tokio::time::sleep(POLL_INTERVAL).await;
+        let registry = app.state::<FinetuningRegistry>();
+        let ids = registry.pollable_ids();
+        for id in ids {
+            let app = app.clone();
+            let permit = semaphore.clone().acquire_owned().await;
+            tauri::async_runtime::spawn(async move {
+                let _permit = permit;
+                poll_one_job(&app, &id).await;
+            });
+        }
+    }
+}
+
+async fn poll_one_job(app: &tauri::AppHandle, job_id: &str) {
+    let status_path = format!("/finetuning/jobs/{}/status", job_id);
+    if let Ok(raw) = http::backend_json_with_opts(
+        app,
+        Method::GET,
+        &status_path,
+        None,
+        None,
+        http::RetryOptions::quick(),
+    )
+    .await
+    {
+        if let Ok(envelope) = serde_json::from_str::<serde_json::Value>(&raw) {
+            if envelope["success"].as_bool() == Some(true) {
+                let data = envelope["data"].clone();
+                if let Some(status) = data.get("status").and_then(|s| s.as_str()) {
+                    app.state::<FinetuningRegistry>()
+                        .set_status(app, job_id, status);
+                }
+                let _ = app.emit(
+                    "finetuning-job-status",
+                    JobStatusEvent { job_id, status: data },
+                );
+            }
+        }
+    }
+
+    let logs_path = format!("/finetuning/jobs/{}/logs", job_id);
+    if let Ok(raw) = http::backend_json_with_opts(
+        app,
+        Method::GET,
+        &logs_path,
+        Some(vec![("limit".to_string(), "200".to_string())]),
+        None,
+        http::RetryOptions::quick(),
+    )
+    .await
+    {
+        if let Ok(envelope) = serde_json::from_str::<serde_json::Value>(&raw) {
+            if envelope["success"].as_bool() == Some(true) {
+                let _ = app.emit(
+                    "finetuning-job-log",
+                    JobLogEvent {
+                        job_id,
+                        logs: envelope["data"].clone(),
+                    },
+                );
+            }
+        }
+    }
+}
 
 #[tauri::command]
 pub async fn estimate_finetuning_cost(
@@ -10,50 +219,12 @@ pub async fn estimate_finetuning_cost(
     model: String,
     platform: String,
 ) -> Result<String, String> {
-    let base_url = get_backend_base_url(&app);
-    let base_escaped = base_url.replace('\\', "\\\\").replace('"', "\\\"");
-    let python_script = format!(
-        r#"
-import sys
-import requests
-import json
-
-base_url = "{}"
-try:
-    response = requests.post(
-        base_url + '/finetuning/estimate',
-        json={{
-            "dataset_size": {},
-            "model": "{}",
-            "platform": "{}"
-        }}
-    )
-    
-    result = {{
-        "success": response.status_code == 200,
-        "data": response.json() if response.status_code == 200 else None,
-        "error": None if response.status_code == 200 else response.text
-    }}
-    print(json.dumps(result))
-except Exception as e:
-    result = {{
-        "success": False,
-        "data": None,
-        "error": str(e)
-    }}
-    print(json.dumps(result))
-"#,
-        base_escaped,
-        dataset_size,
-        model,
-        platform
-    );
-    let output = Command::new("python")
-        .arg("-c")
-        .arg(&python_script)
-        .output()
-        .map_err(|e| format!("Failed to execute Python: {}", e))?;
-    Ok(String::from_utf8_lossy(&output.stdout).to_string())
+    let payload = serde_json::json!({
+        "dataset_size": dataset_size,
+        "model": model,
+        "platform": platform
+    });
+    http::backend_json(&app, Method::POST, "/finetuning/estimate", None, Some(payload)).await
 }
 
 #[tauri::command]
@@ -74,176 +245,155 @@ pub async fn submit_finetuning_job(
         "model": model,
         "api_key": api_key
     });
-    let payload_str = serde_json::to_string(&payload).unwrap_or_else(|_| "{}".to_string());
-    let ts = SystemTime::now()
-        .duration_since(UNIX_EPOCH)
-        .map(|d: Duration| d.as_millis())
-        .unwrap_or(0);
-    let payload_path = std::env::temp_dir().join(format!(
-        "bioforger_finetune_submit_{}_{}.json",
-        std::process::id(),
-        ts
-    ));
-    fs::write(&payload_path, payload_str).map_err(|e| format!("Failed to write temp payload: {}", e))?;
-
-    let base_url = get_backend_base_url(&app);
-    let base_escaped = base_url.replace('\\', "\\\\").replace('"', "\\\"");
-    let python_script = format!(
-        r#"
-import sys
-import requests
-import json
-
-base_url = "{}"
-try:
-    payload_path = sys.argv[1]
-    with open(payload_path, "r", encoding="utf-8") as f:
-        payload = json.load(f)
-    response = requests.post(
-        base_url + '/finetuning/submit',
-        json=payload
+    let raw = http::backend_json_with_opts(
+        &app,
+        Method::POST,
+        "/finetuning/submit",
+        None,
+        Some(payload),
+        http::RetryOptions::finetuning(),
     )
-    
-    result = {{
-        "success": response.status_code == 200,
-        "data": response.json() if response.status_code == 200 else None,
-        "error": None if response.status_code == 200 else response.text
-    }}
-    print(json.dumps(result))
-except Exception as e:
-    result = {{
-        "success": False,
-        "data": None,
-        "error": str(e)
-    }}
-    print(json.dumps(result))
-"#,
-        base_escaped
-    );
-    let output = Command::new("python")
-        .arg("-c")
-        .arg(&python_script)
-        .arg(payload_path.to_string_lossy().to_string())
-        .output()
-        .map_err(|e| {
-            let _ = fs::remove_file(&payload_path);
-            format!("Failed to execute Python: {}", e)
-        })?;
-    let _ = fs::remove_file(&payload_path);
-    Ok(String::from_utf8_lossy(&output.stdout).to_string())
+    .await?;
+
+    if let Ok(envelope) = serde_json::from_str::<serde_json::Value>(&raw) {
+        if envelope["success"].as_bool() == Some(true) {
+            if let Some(job_id) = envelope["data"].get("job_id").and_then(|v| v.as_str()) {
+                app.state::<FinetuningRegistry>().track(&app, job_id);
+            }
+        }
+    }
+    Ok(raw)
 }
 
 #[tauri::command]
 pub async fn get_finetuning_jobs(app: tauri::AppHandle) -> Result<String, String> {
-    let base_url = get_backend_base_url(&app);
-    let base_escaped = base_url.replace('\\', "\\\\").replace('"', "\\\"");
-    let python_script = format!(
-        r#"
-import sys
-import requests
-import json
-
-base_url = "{}"
-try:
-    response = requests.get(base_url + '/finetuning/jobs')
-    result = {{"success": response.status_code == 200, "data": response.json() if response.status_code == 200 else None, "error": None if response.status_code == 200 else response.text}}
-    print(json.dumps(result))
-except Exception as e:
-    result = {{"success": False, "data": None, "error": str(e)}}
-    print(json.dumps(result))
-"#,
-        base_escaped
-    );
-    let output = Command::new("python")
-        .arg("-c")
-        .arg(&python_script)
-        .output()
-        .map_err(|e| format!("Failed to execute Python: {}", e))?;
-    Ok(String::from_utf8_lossy(&output.stdout).to_string())
+    http::backend_json(&app, Method::GET, "/finetuning/jobs", None, None).await
 }
 
 #[tauri::command]
-pub async fn get_job_logs(
-    app: tauri::AppHandle,
-    job_id: String,
-    limit: i32,
-) -> Result<String, String> {
-    let base_url = get_backend_base_url(&app);
-    let base_escaped = base_url.replace('\\', "\\\\").replace('"', "\\\"");
-    let python_script = format!(
-        r#"
-import sys
-import requests
-import json
-
-base_url = "{}"
-job_id = "{}"
-limit = {}
-
-try:
-    response = requests.get(base_url + '/finetuning/jobs/{{}}/logs?limit={{}}'.format(job_id, limit))
-    result = {{
-        "success": response.status_code == 200,
-        "data": response.json() if response.status_code == 200 else None,
-        "error": None if response.status_code == 200 else response.text
-    }}
-    print(json.dumps(result))
-except Exception as e:
-    result = {{
-        "success": False,
-        "data": None,
-        "error": str(e)
-    }}
-    print(json.dumps(result))
-"#,
-        base_escaped,
-        job_id,
-        limit
-    );
-    let output = Command::new("python")
-        .arg("-c")
-        .arg(&python_script)
-        .output()
-        .map_err(|e| format!("Failed to execute Python: {}", e))?;
-    Ok(String::from_utf8_lossy(&output.stdout).to_string())
+pub async fn get_job_logs(app: tauri::AppHandle, job_id: String, limit: i32) -> Result<String, String> {
+    let path = format!("/finetuning/jobs/{}/logs", job_id);
+    http::backend_json(
+        &app,
+        Method::GET,
+        &path,
+        Some(vec![("limit".to_string(), limit.to_string())]),
+        None,
+    )
+    .await
 }
 
+/// Uses `typed_client` so a schema change on the backend's job-status response (a renamed
+/// or dropped required field) fails to deserialize into `FinetuningJob` and comes back as an
+/// error envelope instead of silently handing the frontend `null`.
 #[tauri::command]
 pub async fn get_job_status(app: tauri::AppHandle, job_id: String) -> Result<String, String> {
+    let path = format!("/finetuning/jobs/{}/status", job_id);
+    match typed_client::backend_json_typed::<(), models::FinetuningJob>(&app, Method::GET, &path, None, None).await {
+        Ok(job) => http::json_result(true, serde_json::to_value(job).unwrap_or_default(), None),
+        Err(error) => http::json_result(false, serde_json::Value::Null, Some(error)),
+    }
+}
+
+#[tauri::command]
+pub fn pause_finetuning_job(app: tauri::AppHandle, job_id: String) -> Result<(), String> {
+    app.state::<FinetuningRegistry>().set_paused(&app, &job_id, true);
+    Ok(())
+}
+
+#[tauri::command]
+pub fn resume_finetuning_job(app: tauri::AppHandle, job_id: String) -> Result<(), String> {
+    app.state::<FinetuningRegistry>().set_paused(&app, &job_id, false);
+    Ok(())
+}
+
+#[tauri::command]
+pub fn forget_finetuning_job(app: tauri::AppHandle, job_id: String) -> Result<(), String> {
+    app.state::<FinetuningRegistry>().forget(&app, &job_id);
+    Ok(())
+}
+
+/// Opens one long-lived streaming connection to the backend and forwards new log lines as
+/// `job://{id}/log` events and status changes as `job://{id}/status` events, instead of
+/// making the frontend poll `get_job_logs`/`get_job_status` in a loop. Tracks the last-seen
+/// byte offset so that, when the backend ends the stream before the job is done, the
+/// reconnect resumes without re-emitting lines already delivered. The offset lives only for
+/// this call: a read error returns `Err`, and a new call (e.g. after `stop_following_job`)
+/// starts again from offset 0. Stops on its own once the job reaches a terminal status.
+#[tauri::command]
+pub async fn follow_job_logs(app: tauri::AppHandle, job_id: String) -> Result<(), String> {
+    let registry = app.state::<JobLogStreamRegistry>();
+    let cancel_flag = registry.register(&job_id);
+
+    let log_event = format!("job://{}/log", job_id);
+    let status_event = format!("job://{}/status", job_id);
+
     let base_url = get_backend_base_url(&app);
-    let base_escaped = base_url.replace('\\', "\\\\").replace('"', "\\\"");
-    let python_script = format!(
-        r#"
-import sys
-import requests
-import json
-
-base_url = "{}"
-job_id = "{}"
-
-try:
-    response = requests.get(base_url + '/finetuning/jobs/{{}}/status'.format(job_id))
-    result = {{
-        "success": response.status_code == 200,
-        "data": response.json() if response.status_code == 200 else None,
-        "error": None if response.status_code == 200 else response.text
-    }}
-    print(json.dumps(result))
-except Exception as e:
-    result = {{
-        "success": False,
-        "data": None,
-        "error": str(e)
-    }}
-    print(json.dumps(result))
-"#,
-        base_escaped,
-        job_id
-    );
-    let output = Command::new("python")
-        .arg("-c")
-        .arg(&python_script)
-        .output()
-        .map_err(|e| format!("Failed to execute Python: {}", e))?;
-    Ok(String::from_utf8_lossy(&output.stdout).to_string())
+    let url = format!("{}/finetuning/jobs/{}/logs/stream", base_url, job_id);
+    let client = reqwest::Client::new();
+
+    let mut offset: u64 = 0;
+    let mut carry = String::new();
+
+    let result = async {
+        loop {
+            if cancel_flag.load(Ordering::Relaxed) {
+                return Ok(());
+            }
+
+            let mut resp = client
+                .get(&url)
+                .query(&[("from_offset", offset.to_string())])
+                .send()
+                .await
+                .map_err(|e| format!("Failed to reach backend: {}", e))?;
+
+            if !resp.status().is_success() {
+                let body = resp.text().await.unwrap_or_default();
+                return Err(format!("Backend returned an error: {}", body));
+            }
+
+            while let Some(chunk) = resp.chunk().await.map_err(|e| format!("Stream read failed: {}", e))? {
+                if cancel_flag.load(Ordering::Relaxed) {
+                    return Ok(());
+                }
+                offset += chunk.len() as u64;
+                carry.push_str(&String::from_utf8_lossy(&chunk));
+
+                while let Some(pos) = carry.find('\n') {
+                    let line = carry[..pos].trim().to_string();
+                    carry.drain(..=pos);
+                    if line.is_empty() {
+                        continue;
+                    }
+                    let Ok(event) = serde_json::from_str::<serde_json::Value>(&line) else {
+                        continue;
+                    };
+                    if let Some(text) = event.get("line").and_then(|l| l.as_str()) {
+                        let _ = app.emit(&log_event, JobLogLine { job_id: &job_id, line: text });
+                    }
+                    if let Some(status) = event.get("status").and_then(|s| s.as_str()) {
+                        let _ = app.emit(&status_event, JobStatusUpdate { job_id: &job_id, status });
+                        if TERMINAL_JOB_STATUSES.contains(&status) {
+                            return Ok(());
+                        }
+                    }
+                }
+            }
+        }
+    }
+    .await;
+
+    registry.forget(&job_id);
+    result
+}
+
+/// Cancels an in-flight `follow_job_logs` stream by job id.
+#[tauri::command]
+pub fn stop_following_job(app: tauri::AppHandle, job_id: String) -> Result<(), String> {
+    let registry = app.state::<JobLogStreamRegistry>();
+    if let Some(flag) = registry.cancel_flags.lock().unwrap().get(&job_id) {
+        flag.store(true, Ordering::Relaxed);
+    }
+    Ok(())
 }
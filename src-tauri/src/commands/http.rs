@@ -1,13 +1,62 @@
+use std::collections::HashMap;
 use std::fs;
 use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 
 use base64::Engine;
 use reqwest::Method;
+use serde::Serialize;
 use serde_json::Value;
+use tauri::{Emitter, Manager};
+use tokio::io::{AsyncReadExt, AsyncSeekExt};
 
 use crate::backend_url::get_backend_base_url;
 
-fn json_result(success: bool, data: Value, error: Option<String>) -> Result<String, String> {
+// Shared async client plumbing for every command that talks to the 127.0.0.1:8778 backend.
+// The old per-command `python -c` + `requests` shell-out is gone from this module tree —
+// `backend_json`/`backend_json_with_opts`/`backend_upload_file` below are the shared,
+// parameterized (no string-interpolated scripts) replacement that the request/response
+// commands call into (the streaming and long-poll commands open their own `reqwest::Client`),
+// with `BreakerRegistry`/`UploadRegistry` as the Tauri-managed state backing them.
+
+/// Structured error shape for the `{success, data, error}` envelope, so the frontend can
+/// branch on `kind` (retryable network failure vs. an auth/validation response from the
+/// backend vs. a malformed payload) instead of string-matching a message.
+#[derive(Serialize, serde::Deserialize, Clone)]
+#[serde(tag = "kind")]
+pub enum BioError {
+    /// Could not reach the backend at all (connection refused, timeout, DNS). An open circuit
+    /// breaker is not put in the envelope: the helpers below return it as a plain `Err` string
+    /// before any request is made (`typed_client` then wraps that string in this variant).
+    Network { message: String },
+    /// The backend responded but the body wasn't the JSON we expected.
+    Parse { message: String },
+    /// The backend responded with a 4xx/5xx status; `message` is its raw response text and
+    /// `body` is that response parsed as JSON, if it is JSON.
+    Endpoint {
+        status: u16,
+        message: String,
+        body: Option<Value>,
+    },
+    /// A local precondition failed before any request was made (missing file, cancelled op).
+    Local { message: String },
+}
+
+impl BioError {
+    pub fn endpoint(status: reqwest::StatusCode, raw: &[u8]) -> Self {
+        let text = String::from_utf8_lossy(raw).to_string();
+        let body = serde_json::from_slice::<Value>(raw).ok();
+        BioError::Endpoint {
+            status: status.as_u16(),
+            message: text,
+            body,
+        }
+    }
+}
+
+pub fn json_result(success: bool, data: Value, error: Option<BioError>) -> Result<String, String> {
     serde_json::to_string(&serde_json::json!({
         "success": success,
         "data": data,
@@ -16,6 +65,155 @@ fn json_result(success: bool, data: Value, error: Option<String>) -> Result<Stri
     .map_err(|e| e.to_string())
 }
 
+/// Per-call retry/timeout overrides. Long finetuning calls want a generous timeout and
+/// few attempts; quick config reads want the opposite.
+#[derive(Clone, Copy)]
+pub struct RetryOptions {
+    pub max_attempts: u32,
+    pub timeout: Duration,
+}
+
+impl Default for RetryOptions {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            timeout: Duration::from_secs(30),
+        }
+    }
+}
+
+impl RetryOptions {
+    pub fn finetuning() -> Self {
+        Self {
+            max_attempts: 2,
+            timeout: Duration::from_secs(300),
+        }
+    }
+
+    pub fn quick() -> Self {
+        Self {
+            max_attempts: 4,
+            timeout: Duration::from_secs(5),
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum BreakerState {
+    Closed,
+    Open,
+    HalfOpen,
+}
+
+struct CircuitBreaker {
+    state: BreakerState,
+    consecutive_failures: u32,
+    opened_at: Option<Instant>,
+}
+
+impl CircuitBreaker {
+    fn new() -> Self {
+        Self {
+            state: BreakerState::Closed,
+            consecutive_failures: 0,
+            opened_at: None,
+        }
+    }
+}
+
+const FAILURE_THRESHOLD: u32 = 5;
+const OPEN_COOLDOWN: Duration = Duration::from_secs(30);
+
+/// Per-base-URL circuit breakers, stored as Tauri managed state so every command
+/// sharing a backend URL shares the same trip state. Only 5xx/connection failures
+/// count towards tripping the breaker; 4xx responses are caller errors, not backend health.
+#[derive(Default)]
+pub struct BreakerRegistry {
+    breakers: Mutex<HashMap<String, CircuitBreaker>>,
+}
+
+impl BreakerRegistry {
+    fn guard(&self, base_url: &str) -> Result<(), String> {
+        let mut map = self.breakers.lock().unwrap();
+        let breaker = map.entry(base_url.to_string()).or_insert_with(CircuitBreaker::new);
+        match breaker.state {
+            BreakerState::Closed | BreakerState::HalfOpen => Ok(()),
+            BreakerState::Open => {
+                let cooled_down = breaker.opened_at.map(|t| t.elapsed() >= OPEN_COOLDOWN).unwrap_or(false);
+                if cooled_down {
+                    breaker.state = BreakerState::HalfOpen;
+                    Ok(())
+                } else {
+                    Err(format!("Circuit breaker open for {} (cooling down)", base_url))
+                }
+            }
+        }
+    }
+
+    fn record_success(&self, base_url: &str) {
+        let mut map = self.breakers.lock().unwrap();
+        if let Some(b) = map.get_mut(base_url) {
+            b.state = BreakerState::Closed;
+            b.consecutive_failures = 0;
+            b.opened_at = None;
+        }
+    }
+
+    fn record_failure(&self, base_url: &str) {
+        let mut map = self.breakers.lock().unwrap();
+        let breaker = map.entry(base_url.to_string()).or_insert_with(CircuitBreaker::new);
+        breaker.consecutive_failures += 1;
+        if breaker.consecutive_failures >= FAILURE_THRESHOLD {
+            breaker.state = BreakerState::Open;
+            breaker.opened_at = Some(Instant::now());
+        }
+    }
+}
+
+/// base*2^attempt capped at 5s, plus up to ~150ms of jitter so concurrent retries don't
+/// all land on the backend at once.
+fn backoff_with_jitter(attempt: u32) -> Duration {
+    let base = 200u64.saturating_mul(1u64 << attempt.min(6));
+    let capped = base.min(5_000);
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.subsec_nanos())
+        .unwrap_or(0);
+    Duration::from_millis(capped + (nanos as u64 % 150))
+}
+
+/// Records a request-count and latency-histogram sample for one backend call, labeled by
+/// path and status, so the Prometheus snapshot can break down failures per endpoint.
+fn record_http_metrics(path: &str, status: &str, elapsed: Duration) {
+    metrics::counter!(
+        "bioforge_backend_requests_total",
+        "path" => path.to_string(),
+        "status" => status.to_string()
+    )
+    .increment(1);
+    metrics::histogram!("bioforge_backend_request_duration_seconds", "path" => path.to_string())
+        .record(elapsed.as_secs_f64());
+    tracing::Span::current().record("status", status);
+}
+
+/// Rejects the call immediately, with no network attempt, while the supervisor reports the
+/// backend as `Stopped` or `Failed` — so a command fails with a clear "backend not ready"
+/// error instead of spawning a request that just times out against nothing listening on the
+/// port.
+fn fail_fast_unless_ready(app: &tauri::AppHandle) -> Result<(), String> {
+    let Some(supervisor) = app.try_state::<crate::state::BackendSupervisorState>() else {
+        return Ok(());
+    };
+    let phase = supervisor.snapshot.lock().unwrap().phase;
+    if phase == crate::state::SupervisedPhase::Stopped {
+        return Err("Backend is not ready (supervisor reports it stopped)".to_string());
+    }
+    if phase == crate::state::SupervisedPhase::Failed {
+        return Err("Backend is not ready (supervisor gave up restarting it)".to_string());
+    }
+    Ok(())
+}
+
 pub async fn backend_json(
     app: &tauri::AppHandle,
     method: Method,
@@ -23,108 +221,301 @@ pub async fn backend_json(
     query: Option<Vec<(String, String)>>,
     body: Option<Value>,
 ) -> Result<String, String> {
+    backend_json_with_opts(app, method, path, query, body, RetryOptions::default()).await
+}
+
+#[tracing::instrument(skip(app, query, body, opts), fields(status = tracing::field::Empty, attempts = tracing::field::Empty))]
+pub async fn backend_json_with_opts(
+    app: &tauri::AppHandle,
+    method: Method,
+    path: &str,
+    query: Option<Vec<(String, String)>>,
+    body: Option<Value>,
+    opts: RetryOptions,
+) -> Result<String, String> {
+    fail_fast_unless_ready(app)?;
+
+    let started_at = Instant::now();
     let base_url = get_backend_base_url(app);
     let url = format!("{}{}", base_url, path);
-    let client = reqwest::Client::new();
-    let mut req = client.request(method.clone(), &url);
-    if let Some(ref q) = query {
-        req = req.query(&q);
-    }
-    if let Some(ref b) = body {
-        req = req.json(&b);
-    }
+    let registry = app.state::<BreakerRegistry>();
+    registry.guard(&base_url)?;
+
+    let client = reqwest::Client::builder()
+        .timeout(opts.timeout)
+        .build()
+        .map_err(|e| e.to_string())?;
 
-    let resp = match req.send().await {
-        Ok(r) => r,
-        Err(_) => {
-            std::thread::sleep(std::time::Duration::from_millis(300));
-            let mut retry = client.request(method, &url);
-            if let Some(q) = query.clone() {
-                retry = retry.query(&q);
+    let mut last_error = BioError::Network {
+        message: "Backend request failed".to_string(),
+    };
+    for attempt in 0..opts.max_attempts.max(1) {
+        if attempt > 0 {
+            metrics::counter!("bioforge_backend_retries_total", "path" => path.to_string()).increment(1);
+            tokio::time::sleep(backoff_with_jitter(attempt)).await;
+        }
+
+        let mut req = client.request(method.clone(), &url);
+        if let Some(ref q) = query {
+            req = req.query(q);
+        }
+        if let Some(ref b) = body {
+            req = req.json(b);
+        }
+
+        match req.send().await {
+            Ok(resp) => {
+                let status = resp.status();
+                if status.is_success() {
+                    registry.record_success(&base_url);
+                    record_http_metrics(path, status.as_str(), started_at.elapsed());
+                    let bytes = resp
+                        .bytes()
+                        .await
+                        .map_err(|e| format!("Failed to read response: {}", e))?;
+                    let data = match serde_json::from_slice::<Value>(&bytes) {
+                        Ok(v) => v,
+                        Err(e) => {
+                            return json_result(
+                                false,
+                                Value::Null,
+                                Some(BioError::Parse { message: e.to_string() }),
+                            );
+                        }
+                    };
+                    return json_result(true, data, None);
+                }
+                if status.is_client_error() {
+                    // 4xx is a caller/validation error, not a backend failure: no retry, no breaker trip.
+                    record_http_metrics(path, status.as_str(), started_at.elapsed());
+                    let bytes = resp.bytes().await.unwrap_or_default();
+                    return json_result(false, Value::Null, Some(BioError::endpoint(status, &bytes)));
+                }
+                registry.record_failure(&base_url);
+                record_http_metrics(path, status.as_str(), started_at.elapsed());
+                let bytes = resp.bytes().await.unwrap_or_default();
+                last_error = BioError::endpoint(status, &bytes);
             }
-            if let Some(b) = body.clone() {
-                retry = retry.json(&b);
+            Err(e) => {
+                registry.record_failure(&base_url);
+                record_http_metrics(path, "error", started_at.elapsed());
+                last_error = BioError::Network {
+                    message: format!("Backend request failed (url: {}): {}", url, e),
+                };
             }
-            retry
-                .send()
-                .await
-                .map_err(|e| format!("Backend request failed (url: {}): {}", url, e))?
         }
-    };
-    let status_ok = resp.status().is_success();
-    let bytes = resp
-        .bytes()
-        .await
-        .map_err(|e| format!("Failed to read response: {}", e))?;
+    }
+    json_result(false, Value::Null, Some(last_error))
+}
+
+const UPLOAD_CHUNK_SIZE: u64 = 4 * 1024 * 1024;
+
+/// Tracks in-flight resumable uploads so `cancel_upload` can stop one by id between chunks.
+#[derive(Default)]
+pub struct UploadRegistry {
+    cancel_flags: Mutex<HashMap<String, Arc<AtomicBool>>>,
+}
+
+impl UploadRegistry {
+    fn register(&self, upload_id: &str) -> Arc<AtomicBool> {
+        let flag = Arc::new(AtomicBool::new(false));
+        self.cancel_flags
+            .lock()
+            .unwrap()
+            .insert(upload_id.to_string(), flag.clone());
+        flag
+    }
 
-    if status_ok {
-        let data = serde_json::from_slice::<Value>(&bytes).unwrap_or(Value::Null);
-        json_result(true, data, None)
-    } else {
-        let error = String::from_utf8_lossy(&bytes).to_string();
-        json_result(false, Value::Null, Some(error))
+    fn forget(&self, upload_id: &str) {
+        self.cancel_flags.lock().unwrap().remove(upload_id);
     }
 }
 
+#[derive(Serialize, Clone)]
+struct UploadProgress<'a> {
+    upload_id: &'a str,
+    bytes_sent: u64,
+    total_bytes: u64,
+}
+
+/// Asks the backend how much of `upload_id` it has already received, so a retried/resumed
+/// upload only sends the missing tail. Any failure to probe is treated as "start from zero".
+async fn probe_resume_offset(client: &reqwest::Client, url: &str, upload_id: &str) -> u64 {
+    let resp = client
+        .get(format!("{}/resume", url))
+        .query(&[("upload_id", upload_id)])
+        .send()
+        .await;
+    match resp {
+        Ok(r) if r.status().is_success() => r
+            .json::<Value>()
+            .await
+            .ok()
+            .and_then(|v| v.get("bytes_received").and_then(|b| b.as_u64()))
+            .unwrap_or(0),
+        _ => 0,
+    }
+}
+
+/// Streams `file_path` to the backend in fixed-size chunks using Content-Range, instead of
+/// loading the whole file into memory. Resumes from whatever offset the backend reports it
+/// already has, retries individual chunks (not the whole file) on failure, and emits
+/// `upload-progress` events keyed by `upload_id` so the frontend can show a progress bar and
+/// cancel via `cancel_upload`. Each chunk is sent as a raw `application/octet-stream` body
+/// rather than multipart, since the backend only ever needs one file per request and this
+/// already gets us streaming + resumability without the multipart boundary bookkeeping.
+#[tracing::instrument(skip(app))]
 pub async fn backend_upload_file(
     app: &tauri::AppHandle,
     path: &str,
     file_path: &str,
+    upload_id: &str,
 ) -> Result<String, String> {
     let p = Path::new(file_path);
     if !p.exists() {
-        return json_result(false, Value::Null, Some("File not found".to_string()));
+        return json_result(
+            false,
+            Value::Null,
+            Some(BioError::Local {
+                message: "File not found".to_string(),
+            }),
+        );
     }
     let filename = p
         .file_name()
         .and_then(|n| n.to_str())
         .ok_or("Invalid file name")?
         .to_string();
-    let bytes = fs::read(p).map_err(|e| format!("Failed to read file: {}", e))?;
-
-    let build_form = || -> Result<reqwest::multipart::Form, String> {
-        let part = reqwest::multipart::Part::bytes(bytes.clone())
-            .file_name(filename.clone())
-            .mime_str("application/octet-stream")
-            .map_err(|e| format!("Failed to prepare upload: {}", e))?;
-        Ok(reqwest::multipart::Form::new().part("file", part))
-    };
+    let total_bytes = fs::metadata(p)
+        .map_err(|e| format!("Failed to stat file: {}", e))?
+        .len();
 
     let base_url = get_backend_base_url(app);
     let url = format!("{}{}", base_url, path);
+    let registry = app.state::<BreakerRegistry>();
+    registry.guard(&base_url)?;
     let client = reqwest::Client::new();
-    let resp = match client
-        .post(url.clone())
-        .multipart(build_form()?)
-        .send()
+
+    let uploads = app.state::<UploadRegistry>();
+    let cancel_flag = uploads.register(upload_id);
+
+    let mut bytes_sent = probe_resume_offset(&client, &url, upload_id).await.min(total_bytes);
+
+    let mut file = tokio::fs::File::open(p)
         .await
-    {
-        Ok(r) => r,
-        Err(_) => {
-            std::thread::sleep(std::time::Duration::from_millis(300));
-            client
-                .post(&url)
-                .multipart(build_form()?)
+        .map_err(|e| format!("Failed to open file: {}", e))?;
+    file.seek(std::io::SeekFrom::Start(bytes_sent))
+        .await
+        .map_err(|e| format!("Failed to seek file: {}", e))?;
+
+    let mut buf = vec![0u8; UPLOAD_CHUNK_SIZE as usize];
+    let opts = RetryOptions::finetuning();
+    let mut final_body: Option<Value> = None;
+
+    while bytes_sent < total_bytes {
+        if cancel_flag.load(Ordering::Relaxed) {
+            uploads.forget(upload_id);
+            return json_result(
+                false,
+                Value::Null,
+                Some(BioError::Local {
+                    message: "Upload cancelled".to_string(),
+                }),
+            );
+        }
+
+        let to_read = ((total_bytes - bytes_sent).min(UPLOAD_CHUNK_SIZE)) as usize;
+        let n = file
+            .read(&mut buf[..to_read])
+            .await
+            .map_err(|e| format!("Failed to read file: {}", e))?;
+        if n == 0 {
+            break;
+        }
+        let chunk = buf[..n].to_vec();
+        let range_end = bytes_sent + n as u64 - 1;
+
+        let mut chunk_error = BioError::Network {
+            message: "Chunk upload failed".to_string(),
+        };
+        let mut chunk_ok = false;
+        for attempt in 0..opts.max_attempts {
+            if attempt > 0 {
+                tokio::time::sleep(backoff_with_jitter(attempt)).await;
+            }
+            let resp = client
+                .put(&url)
+                .header("Content-Range", format!("bytes {}-{}/{}", bytes_sent, range_end, total_bytes))
+                .header("Content-Type", "application/octet-stream")
+                .header("X-Upload-Id", upload_id)
+                .header("X-File-Name", &filename)
+                .body(chunk.clone())
                 .send()
-                .await
-                .map_err(|e| format!("Backend upload failed (url: {}): {}", url, e))?
+                .await;
+            match resp {
+                Ok(r) if r.status().is_success() => {
+                    registry.record_success(&base_url);
+                    final_body = r.json::<Value>().await.ok();
+                    chunk_ok = true;
+                    break;
+                }
+                Ok(r) if r.status().is_client_error() => {
+                    uploads.forget(upload_id);
+                    let status = r.status();
+                    let body = r.bytes().await.unwrap_or_default();
+                    return json_result(false, Value::Null, Some(BioError::endpoint(status, &body)));
+                }
+                Ok(r) => {
+                    registry.record_failure(&base_url);
+                    let status = r.status();
+                    let body = r.bytes().await.unwrap_or_default();
+                    chunk_error = BioError::endpoint(status, &body);
+                }
+                Err(e) => {
+                    registry.record_failure(&base_url);
+                    chunk_error = BioError::Network {
+                        message: format!("Chunk upload failed (url: {}): {}", url, e),
+                    };
+                }
+            }
+        }
+        if !chunk_ok {
+            uploads.forget(upload_id);
+            return json_result(false, Value::Null, Some(chunk_error));
         }
-    };
 
-    let status_ok = resp.status().is_success();
-    let bytes = resp
-        .bytes()
-        .await
-        .map_err(|e| format!("Failed to read response: {}", e))?;
-    if status_ok {
-        let data = serde_json::from_slice::<Value>(&bytes).unwrap_or(Value::Null);
-        json_result(true, data, None)
-    } else {
-        let error = String::from_utf8_lossy(&bytes).to_string();
-        json_result(false, Value::Null, Some(error))
+        bytes_sent += n as u64;
+        metrics::counter!("bioforge_backend_upload_bytes_total", "path" => path.to_string()).increment(n as u64);
+        let _ = app.emit(
+            "upload-progress",
+            UploadProgress {
+                upload_id,
+                bytes_sent,
+                total_bytes,
+            },
+        );
+    }
+
+    uploads.forget(upload_id);
+    json_result(true, final_body.unwrap_or(Value::Null), None)
+}
+
+/// Cancels an in-flight `backend_upload_file` call by upload id between chunks.
+#[tauri::command]
+pub fn cancel_upload(app: tauri::AppHandle, upload_id: String) -> Result<(), String> {
+    if let Some(flag) = app
+        .state::<UploadRegistry>()
+        .cancel_flags
+        .lock()
+        .unwrap()
+        .get(&upload_id)
+    {
+        flag.store(true, Ordering::Relaxed);
     }
+    Ok(())
 }
 
+#[tracing::instrument(skip(app, query))]
 pub async fn backend_binary_with_version(
     app: &tauri::AppHandle,
     path: &str,
@@ -132,39 +523,66 @@ pub async fn backend_binary_with_version(
 ) -> Result<String, String> {
     let base_url = get_backend_base_url(app);
     let url = format!("{}{}", base_url, path);
+    let registry = app.state::<BreakerRegistry>();
+    registry.guard(&base_url)?;
     let client = reqwest::Client::new();
-    let resp = client
-        .get(url)
-        .query(&query)
-        .send()
-        .await
-        .map_err(|e| format!("Backend request failed: {}", e))?;
-    let status_ok = resp.status().is_success();
-    if !status_ok {
-        let error = resp
-            .text()
+
+    let started_at = Instant::now();
+    let opts = RetryOptions::default();
+    let mut last_error = BioError::Network {
+        message: "Backend request failed".to_string(),
+    };
+    for attempt in 0..opts.max_attempts {
+        if attempt > 0 {
+            metrics::counter!("bioforge_backend_retries_total", "path" => path.to_string()).increment(1);
+            tokio::time::sleep(backoff_with_jitter(attempt)).await;
+        }
+        let resp = match client.get(&url).query(&query).send().await {
+            Ok(r) => r,
+            Err(e) => {
+                registry.record_failure(&base_url);
+                record_http_metrics(path, "error", started_at.elapsed());
+                last_error = BioError::Network {
+                    message: format!("Backend request failed: {}", e),
+                };
+                continue;
+            }
+        };
+        let status = resp.status();
+        if status.is_client_error() {
+            record_http_metrics(path, status.as_str(), started_at.elapsed());
+            let body = resp.bytes().await.unwrap_or_default();
+            return json_result(false, Value::Null, Some(BioError::endpoint(status, &body)));
+        }
+        if !status.is_success() {
+            registry.record_failure(&base_url);
+            record_http_metrics(path, status.as_str(), started_at.elapsed());
+            let body = resp.bytes().await.unwrap_or_default();
+            last_error = BioError::endpoint(status, &body);
+            continue;
+        }
+
+        registry.record_success(&base_url);
+        record_http_metrics(path, status.as_str(), started_at.elapsed());
+        let version = resp
+            .headers()
+            .get("X-Preview-Version")
+            .or_else(|| resp.headers().get("x-preview-version"))
+            .and_then(|v| v.to_str().ok())
+            .unwrap_or("")
+            .to_string();
+        let bytes = resp
+            .bytes()
             .await
-            .unwrap_or_else(|e| format!("Failed to read error body: {}", e));
-        return json_result(false, Value::Null, Some(error));
+            .map_err(|e| format!("Failed to read response bytes: {}", e))?;
+        let encoded = base64::engine::general_purpose::STANDARD.encode(bytes);
+        return serde_json::to_string(&serde_json::json!({
+            "success": true,
+            "data": encoded,
+            "version": version,
+            "error": null
+        }))
+        .map_err(|e| e.to_string());
     }
-
-    let version = resp
-        .headers()
-        .get("X-Preview-Version")
-        .or_else(|| resp.headers().get("x-preview-version"))
-        .and_then(|v| v.to_str().ok())
-        .unwrap_or("")
-        .to_string();
-    let bytes = resp
-        .bytes()
-        .await
-        .map_err(|e| format!("Failed to read response bytes: {}", e))?;
-    let encoded = base64::engine::general_purpose::STANDARD.encode(bytes);
-    serde_json::to_string(&serde_json::json!({
-        "success": true,
-        "data": encoded,
-        "version": version,
-        "error": null
-    }))
-    .map_err(|e| e.to_string())
+    json_result(false, Value::Null, Some(last_error))
 }
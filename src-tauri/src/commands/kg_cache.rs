@@ -0,0 +1,262 @@
+//! In-memory, sorted-vector-backed cache behind `get_knowledge_points`/
+//! `get_knowledge_points_for_graph`. Knowledge points are read far more often than they're
+//! mutated, so instead of re-fetching and re-sorting the full set from the backend on every
+//! call, this builds one sorted-by-id snapshot (plus a keyword index sorted for merge-join
+//! intersections) and serves reads from it with binary search. Mutating commands
+//! (`create_knowledge_point`, `delete_knowledge_points_batch`,
+//! `update_knowledge_point_weight`, keyword add/remove) still write through to the backend
+//! first, then stage the resulting change in a small `BTreeMap` overlay rather than patching
+//! the sorted vectors in place — a mid-vector insert/remove is O(n) anyway, so batching edits
+//! into one rebuild amortizes that cost across a burst of them instead of paying it per edit.
+
+use std::collections::BTreeMap;
+use std::sync::Mutex;
+
+use reqwest::Method;
+
+use crate::commands::http::BioError;
+use crate::commands::models::{KnowledgePoint, KnowledgePointPage};
+use crate::commands::typed_client::backend_json_typed;
+
+/// Page size used when pulling the full knowledge-point set from the backend to (re)build the
+/// snapshot. Large enough that a typical graph fits in a handful of requests.
+const LOAD_PAGE_SIZE: i32 = 500;
+/// Once this many edits have piled up in the overlay, the next read triggers a full rebuild
+/// instead of merging the overlay on top of an increasingly stale snapshot.
+const REBUILD_THRESHOLD: usize = 64;
+
+#[derive(Clone)]
+enum PendingEdit {
+    Upsert(KnowledgePoint),
+    Delete,
+}
+
+#[derive(Default)]
+struct CacheSnapshot {
+    /// All known points, sorted by `id`; `find_current` binary-searches this rather than
+    /// scanning.
+    by_id: Vec<KnowledgePoint>,
+    /// `(keyword, id)` pairs sorted by keyword, then id, so "points sharing keyword X" is a sorted
+    /// range lookup and "points sharing X and Y" is a merge-join over two such ranges.
+    by_keyword: Vec<(String, i32)>,
+}
+
+impl CacheSnapshot {
+    fn build(mut points: Vec<KnowledgePoint>) -> Self {
+        points.sort_by_key(|p| p.id);
+        let mut by_keyword: Vec<(String, i32)> = points
+            .iter()
+            .flat_map(|p| p.keywords.iter().map(move |k| (k.0.clone(), p.id)))
+            .collect();
+        by_keyword.sort();
+        CacheSnapshot { by_id: points, by_keyword }
+    }
+
+    fn ids_for_keyword(&self, keyword: &str) -> Vec<i32> {
+        let start = self.by_keyword.partition_point(|(k, _)| k.as_str() < keyword);
+        self.by_keyword[start..]
+            .iter()
+            .take_while(|(k, _)| k == keyword)
+            .map(|(_, id)| *id)
+            .collect()
+    }
+}
+
+/// Ids of points carrying `keyword`, scanned directly off an overlay-merged point list rather
+/// than the snapshot's sorted keyword index — used when the overlay is non-empty and the
+/// index can't be trusted.
+fn ids_for_keyword_in(points: &[KnowledgePoint], keyword: &str) -> Vec<i32> {
+    points
+        .iter()
+        .filter(|p| p.keywords.iter().any(|k| k.0 == keyword))
+        .map(|p| p.id)
+        .collect()
+}
+
+/// Merge-join of two already-sorted id lists — the "points sharing keyword X and Y" query —
+/// instead of building and hashing two sets.
+fn merge_join_ids(mut a: Vec<i32>, mut b: Vec<i32>) -> Vec<i32> {
+    a.sort_unstable();
+    b.sort_unstable();
+    let mut out = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < a.len() && j < b.len() {
+        match a[i].cmp(&b[j]) {
+            std::cmp::Ordering::Less => i += 1,
+            std::cmp::Ordering::Greater => j += 1,
+            std::cmp::Ordering::Equal => {
+                out.push(a[i]);
+                i += 1;
+                j += 1;
+            }
+        }
+    }
+    out
+}
+
+/// Tauri-managed cache of the knowledge-point graph: an immutable sorted snapshot plus a
+/// small overlay of edits staged since it was last built.
+#[derive(Default)]
+pub struct KnowledgeGraphCache {
+    snapshot: Mutex<CacheSnapshot>,
+    overlay: Mutex<BTreeMap<i32, PendingEdit>>,
+    built: std::sync::atomic::AtomicBool,
+}
+
+impl KnowledgeGraphCache {
+    /// Points sharing all of `keywords` (intersection via repeated merge-joins against the
+    /// first keyword's set), reading the current overlay-merged view.
+    pub fn ids_sharing_keywords(&self, keywords: &[String]) -> Vec<i32> {
+        let mut iter = keywords.iter();
+        let Some(first) = iter.next() else { return Vec::new() };
+
+        if self.overlay.lock().unwrap().is_empty() {
+            let snapshot = self.snapshot.lock().unwrap();
+            let mut ids = snapshot.ids_for_keyword(first);
+            for kw in iter {
+                ids = merge_join_ids(ids, snapshot.ids_for_keyword(kw));
+            }
+            return ids;
+        }
+
+        // The snapshot's keyword index doesn't know about staged deletes/weight/excluded
+        // edits; scan the overlay-merged view instead so a point staged for deletion (or
+        // whose keywords otherwise changed) doesn't linger in results until the next rebuild.
+        let merged = self.view();
+        let mut ids = ids_for_keyword_in(&merged, first);
+        for kw in iter {
+            ids = merge_join_ids(ids, ids_for_keyword_in(&merged, kw));
+        }
+        ids
+    }
+
+    /// Current overlay-merged view of every known point, sorted by id. Cheap when the overlay
+    /// is empty (the common case between edits); otherwise folds the overlay's upserts/deletes
+    /// over a clone of the snapshot.
+    fn view(&self) -> Vec<KnowledgePoint> {
+        let snapshot = self.snapshot.lock().unwrap();
+        let overlay = self.overlay.lock().unwrap();
+        if overlay.is_empty() {
+            return snapshot.by_id.clone();
+        }
+        let mut merged: BTreeMap<i32, KnowledgePoint> =
+            snapshot.by_id.iter().map(|p| (p.id, p.clone())).collect();
+        for (id, edit) in overlay.iter() {
+            match edit {
+                PendingEdit::Upsert(point) => {
+                    merged.insert(*id, point.clone());
+                }
+                PendingEdit::Delete => {
+                    merged.remove(id);
+                }
+            }
+        }
+        merged.into_values().collect()
+    }
+
+    fn needs_rebuild(&self) -> bool {
+        !self.built.load(std::sync::atomic::Ordering::Acquire)
+            || self.overlay.lock().unwrap().len() >= REBUILD_THRESHOLD
+    }
+
+    async fn rebuild(&self, app: &tauri::AppHandle) -> Result<(), BioError> {
+        let points = load_all_from_backend(app).await?;
+        *self.snapshot.lock().unwrap() = CacheSnapshot::build(points);
+        self.overlay.lock().unwrap().clear();
+        self.built.store(true, std::sync::atomic::Ordering::Release);
+        Ok(())
+    }
+
+    /// Rebuilds from the backend when the cache has never been built or the overlay has grown
+    /// past `REBUILD_THRESHOLD`, then returns the current overlay-merged view.
+    pub async fn read_through(&self, app: &tauri::AppHandle) -> Result<Vec<KnowledgePoint>, BioError> {
+        if self.needs_rebuild() {
+            self.rebuild(app).await?;
+        }
+        Ok(self.view())
+    }
+
+    /// Stages an upsert (after the backend write that produced `point` has already
+    /// succeeded) so the next read reflects it without a full rebuild.
+    pub fn stage_upsert(&self, point: KnowledgePoint) {
+        self.overlay.lock().unwrap().insert(point.id, PendingEdit::Upsert(point));
+    }
+
+    /// Stages a delete for each id (after the backend delete has already succeeded).
+    pub fn stage_delete(&self, ids: &[i32]) {
+        let mut overlay = self.overlay.lock().unwrap();
+        for id in ids {
+            overlay.insert(*id, PendingEdit::Delete);
+        }
+    }
+
+    /// Invalidates the cache outright so the next read does a full rebuild — used for edits
+    /// (keyword add/remove) where staging a partial `KnowledgePoint` would risk the overlay
+    /// disagreeing with the backend about `keywords`.
+    pub fn invalidate(&self) {
+        self.built.store(false, std::sync::atomic::Ordering::Release);
+        self.overlay.lock().unwrap().clear();
+    }
+
+    /// The currently-known copy of `id` (overlay edit if staged, otherwise a binary search of
+    /// the snapshot), for mutating commands that only know the single field they changed and
+    /// need the rest of the row to stage a full `Upsert`.
+    fn find_current(&self, id: i32) -> Option<KnowledgePoint> {
+        let overlay = self.overlay.lock().unwrap();
+        if let Some(edit) = overlay.get(&id) {
+            return match edit {
+                PendingEdit::Upsert(point) => Some(point.clone()),
+                PendingEdit::Delete => None,
+            };
+        }
+        let snapshot = self.snapshot.lock().unwrap();
+        snapshot
+            .by_id
+            .binary_search_by_key(&id, |p| p.id)
+            .ok()
+            .map(|idx| snapshot.by_id[idx].clone())
+    }
+
+    /// Stages a weight update for `id`, after the backend write already succeeded. A no-op if
+    /// the cache doesn't currently know about `id` — the next rebuild will pick it up anyway.
+    pub fn stage_weight(&self, id: i32, weight: f64) {
+        if let Some(mut point) = self.find_current(id) {
+            point.weight = weight;
+            self.stage_upsert(point);
+        }
+    }
+
+    /// Stages an `excluded` flag update for `id`, mirroring `stage_weight`.
+    pub fn stage_excluded(&self, id: i32, excluded: bool) {
+        if let Some(mut point) = self.find_current(id) {
+            point.excluded = excluded;
+            self.stage_upsert(point);
+        }
+    }
+}
+
+async fn load_all_from_backend(app: &tauri::AppHandle) -> Result<Vec<KnowledgePoint>, BioError> {
+    let mut all = Vec::new();
+    let mut page = 1;
+    loop {
+        let query = vec![
+            ("page".to_string(), page.to_string()),
+            ("page_size".to_string(), LOAD_PAGE_SIZE.to_string()),
+        ];
+        let batch: KnowledgePointPage = backend_json_typed::<(), KnowledgePointPage>(
+            app,
+            Method::GET,
+            "/documents/knowledge-points",
+            Some(query),
+            None,
+        )
+        .await?;
+        let got = batch.items.len();
+        all.extend(batch.items);
+        if got < LOAD_PAGE_SIZE as usize || all.len() as i32 >= batch.total {
+            break;
+        }
+        page += 1;
+    }
+    Ok(all)
+}
@@ -1,5 +1,10 @@
-use std::process::Command;
-use crate::backend_url::get_backend_base_url;
+use reqwest::Method;
+
+use crate::commands::features::load_runtime_features;
+use crate::commands::http::{self, BioError};
+use crate::commands::kg_cache::KnowledgeGraphCache;
+use crate::commands::models::{KnowledgePoint, KnowledgePointPage, Keyword};
+use crate::commands::typed_client::backend_json_typed;
 
 const GRAPH_PAGE_SIZE: i32 = 500;
 
@@ -10,47 +15,41 @@ pub async fn get_knowledge_points(
     page_size: Option<i32>,
     document_id: Option<i32>,
     min_weight: Option<f64>,
-) -> Result<String, String> {
-    let base_url = get_backend_base_url(&app);
-    let page_val = page.unwrap_or(1);
-    let page_size_val = page_size.unwrap_or(50);
-    let mut url = format!("{}/documents/knowledge-points?page={}&page_size={}", base_url, page_val, page_size_val);
-    if let Some(doc_id) = document_id {
-        url.push_str(&format!("&document_id={}", doc_id));
-    }
-    if let Some(w) = min_weight {
-        url.push_str(&format!("&min_weight={}", w));
-    }
-    let python_script = format!(
-        r#"
-import sys
-import requests
-import json
+) -> Result<KnowledgePointPage, BioError> {
+    use tauri::Manager;
+
+    let page = page.unwrap_or(1).max(1);
+    let page_size = page_size.unwrap_or(50).max(1);
+    // min_weight is a 1-5 relevance floor; clamp here so a typo'd caller can't silently send
+    // an out-of-range filter.
+    let min_weight = min_weight.map(|w| w.clamp(1.0, 5.0));
+
+    let cache = app.state::<KnowledgeGraphCache>();
+    let all = cache.read_through(&app).await?;
+    let filtered: Vec<KnowledgePoint> = all
+        .into_iter()
+        .filter(|p| document_id.map(|id| p.document_id == id).unwrap_or(true))
+        .filter(|p| min_weight.map(|w| p.weight >= w).unwrap_or(true))
+        .collect();
+
+    let total = filtered.len() as i32;
+    let start = ((page - 1) as usize).saturating_mul(page_size as usize);
+    let items = filtered.into_iter().skip(start).take(page_size as usize).collect();
+
+    Ok(KnowledgePointPage { items, page, page_size, total })
+}
 
-try:
-    response = requests.get('{}')
-    result = {{
-        "success": response.status_code == 200,
-        "data": response.json() if response.status_code == 200 else None,
-        "error": None if response.status_code == 200 else response.text
-    }}
-    print(json.dumps(result))
-except Exception as e:
-    result = {{
-        "success": False,
-        "data": None,
-        "error": str(e)
-    }}
-    print(json.dumps(result))
-"#,
-        url
-    );
-    let output = Command::new("python")
-        .arg("-c")
-        .arg(python_script)
-        .output()
-        .map_err(|e| format!("Failed to execute Python: {}", e))?;
-    Ok(String::from_utf8_lossy(&output.stdout).to_string())
+/// Points sharing every keyword in `keywords`, served from the cache's keyword index via
+/// merge-join rather than a per-keyword backend round trip.
+#[tauri::command]
+pub async fn get_knowledge_points_sharing_keywords(
+    app: tauri::AppHandle,
+    keywords: Vec<String>,
+) -> Result<Vec<i32>, BioError> {
+    use tauri::Manager;
+    let cache = app.state::<KnowledgeGraphCache>();
+    cache.read_through(&app).await?;
+    Ok(cache.ids_sharing_keywords(&keywords))
 }
 
 #[tauri::command]
@@ -59,89 +58,70 @@ pub async fn get_knowledge_points_for_graph(
     page: Option<i32>,
     min_weight: Option<f64>,
 ) -> Result<String, String> {
-    let base_url = get_backend_base_url(&app);
-    let page_val = page.unwrap_or(1);
-    let min_val = min_weight.unwrap_or(1.0);
-    let min_val = if min_val < 1.0 {
-        1.0
-    } else if min_val > 5.0 {
-        5.0
-    } else {
-        min_val
-    };
-    let url = format!(
-        "{}/documents/knowledge-points?page={}&page_size={}&min_weight={}",
-        base_url,
-        page_val,
-        GRAPH_PAGE_SIZE,
-        min_val
-    );
-    let python_script = format!(
-        r#"
-import sys
-import requests
-import json
+    use tauri::Manager;
+
+    let min_val = min_weight.unwrap_or(1.0).clamp(1.0, 5.0);
+
+    // Experimental: embeddings are heavy per-call payloads that don't belong in the cached
+    // `KnowledgePoint` shape, so that path bypasses the cache entirely and always goes
+    // straight to the backend, same as before this cache existed.
+    if load_runtime_features(&app).graph_embeddings {
+        let query = vec![
+            ("page".to_string(), page.unwrap_or(1).to_string()),
+            ("page_size".to_string(), GRAPH_PAGE_SIZE.to_string()),
+            ("min_weight".to_string(), min_val.to_string()),
+            ("include_embeddings".to_string(), "true".to_string()),
+        ];
+        return http::backend_json(&app, Method::GET, "/documents/knowledge-points", Some(query), None).await;
+    }
 
-try:
-    response = requests.get('{}')
-    result = {{
-        "success": response.status_code == 200,
-        "data": response.json() if response.status_code == 200 else None,
-        "error": None if response.status_code == 200 else response.text
-    }}
-    print(json.dumps(result))
-except Exception as e:
-    result = {{
-        "success": False,
-        "data": None,
-        "error": str(e)
-    }}
-    print(json.dumps(result))
-"#,
-        url
-    );
-    let output = Command::new("python")
-        .arg("-c")
-        .arg(python_script)
-        .output()
-        .map_err(|e| format!("Failed to execute Python: {}", e))?;
-    Ok(String::from_utf8_lossy(&output.stdout).to_string())
+    let cache = app.state::<KnowledgeGraphCache>();
+    let all = cache
+        .read_through(&app)
+        .await
+        .map_err(|e| serde_json::to_string(&e).unwrap_or_else(|_| "knowledge-point cache rebuild failed".to_string()))?;
+    let filtered: Vec<KnowledgePoint> = all.into_iter().filter(|p| p.weight >= min_val).collect();
+
+    let total = filtered.len() as i32;
+    let page = page.unwrap_or(1).max(1);
+    let start = ((page - 1) as usize).saturating_mul(GRAPH_PAGE_SIZE as usize);
+    let items: Vec<KnowledgePoint> =
+        filtered.into_iter().skip(start).take(GRAPH_PAGE_SIZE as usize).collect();
+
+    serde_json::to_string(&KnowledgePointPage {
+        items,
+        page,
+        page_size: GRAPH_PAGE_SIZE,
+        total,
+    })
+    .map_err(|e| e.to_string())
+}
+
+/// True when a `backend_json` envelope reports success. `backend_json` resolves to `Ok` for
+/// most backend-reported failures too (success/failure lives inside the JSON, not the Rust
+/// `Result`), so cache staging must gate on this rather than on the outer `Result`.
+fn envelope_succeeded(raw: &str) -> bool {
+    serde_json::from_str::<serde_json::Value>(raw)
+        .map(|envelope| envelope["success"].as_bool() == Some(true))
+        .unwrap_or(false)
 }
 
 #[tauri::command]
 pub async fn delete_knowledge_points_batch(app: tauri::AppHandle, ids: Vec<i32>) -> Result<String, String> {
-    let base_url = get_backend_base_url(&app);
-    let base_escaped = base_url.replace('\\', "\\\\").replace('"', "\\\"");
-    let ids_json = serde_json::to_string(&ids).unwrap_or_else(|_| "[]".to_string());
-    let python_script = format!(
-        r#"
-import sys
-import requests
-import json
-
-base_url = "{}"
-ids = json.loads('{}')
-try:
-    response = requests.delete(base_url + '/documents/knowledge-points/batch', json={{"ids": ids}})
-    result = {{
-        "success": response.status_code == 200,
-        "data": response.json() if response.status_code == 200 else None,
-        "error": None if response.status_code == 200 else response.text
-    }}
-    print(json.dumps(result))
-except Exception as e:
-    result = {{"success": False, "data": None, "error": str(e)}}
-    print(json.dumps(result))
-"#,
-        base_escaped,
-        ids_json.replace('\\', "\\\\").replace('\'', "\\'")
-    );
-    let output = Command::new("python")
-        .arg("-c")
-        .arg(&python_script)
-        .output()
-        .map_err(|e| format!("Failed to execute Python: {}", e))?;
-    Ok(String::from_utf8_lossy(&output.stdout).to_string())
+    use tauri::Manager;
+    let payload = serde_json::json!({ "ids": ids });
+    let result = http::backend_json(
+        &app,
+        Method::DELETE,
+        "/documents/knowledge-points/batch",
+        None,
+        Some(payload),
+    )
+    .await?;
+    if envelope_succeeded(&result) {
+        app.state::<KnowledgeGraphCache>().stage_delete(&ids);
+    }
+    Ok(result)
 }
 
 #[tauri::command]
@@ -150,37 +130,14 @@ pub async fn update_knowledge_point_weight(
     kp_id: i32,
     weight: f64,
 ) -> Result<String, String> {
-    let base_url = get_backend_base_url(&app);
-    let base_escaped = base_url.replace('\\', "\\\\").replace('"', "\\\"");
-    let python_script = format!(
-        r#"
-import sys
-import requests
-import json
-
-base_url = "{}"
-try:
-    response = requests.patch(base_url + '/documents/knowledge-points/{}', json={{ "weight": {} }})
-    result = {{
-        "success": response.status_code == 200,
-        "data": response.json() if response.status_code == 200 else None,
-        "error": None if response.status_code == 200 else response.text
-    }}
-    print(json.dumps(result))
-except Exception as e:
-    result = {{"success": False, "data": None, "error": str(e)}}
-    print(json.dumps(result))
-"#,
-        base_escaped,
-        kp_id,
-        weight
-    );
-    let output = Command::new("python")
-        .arg("-c")
-        .arg(&python_script)
-        .output()
-        .map_err(|e| format!("Failed to execute Python: {}", e))?;
-    Ok(String::from_utf8_lossy(&output.stdout).to_string())
+    use tauri::Manager;
+    let path = format!("/documents/knowledge-points/{}", kp_id);
+    let payload = serde_json::json!({ "weight": weight });
+    let result = http::backend_json(&app, Method::PATCH, &path, None, Some(payload)).await?;
+    if envelope_succeeded(&result) {
+        app.state::<KnowledgeGraphCache>().stage_weight(kp_id, weight);
+    }
+    Ok(result)
 }
 
 #[tauri::command]
@@ -189,38 +146,14 @@ pub async fn update_knowledge_point_excluded(
     kp_id: i32,
     excluded: bool,
 ) -> Result<String, String> {
-    let base_url = get_backend_base_url(&app);
-    let base_escaped = base_url.replace('\\', "\\\\").replace('"', "\\\"");
-    let excluded_str = if excluded { "True" } else { "False" };
-    let python_script = format!(
-        r#"
-import sys
-import requests
-import json
-
-base_url = "{}"
-try:
-    response = requests.patch(base_url + '/documents/knowledge-points/{}/excluded', json={{ "excluded": {} }})
-    result = {{
-        "success": response.status_code == 200,
-        "data": response.json() if response.status_code == 200 else None,
-        "error": None if response.status_code == 200 else response.text
-    }}
-    print(json.dumps(result))
-except Exception as e:
-    result = {{"success": False, "data": None, "error": str(e)}}
-    print(json.dumps(result))
-"#,
-        base_escaped,
-        kp_id,
-        excluded_str
-    );
-    let output = Command::new("python")
-        .arg("-c")
-        .arg(&python_script)
-        .output()
-        .map_err(|e| format!("Failed to execute Python: {}", e))?;
-    Ok(String::from_utf8_lossy(&output.stdout).to_string())
+    use tauri::Manager;
+    let path = format!("/documents/knowledge-points/{}/excluded", kp_id);
+    let payload = serde_json::json!({ "excluded": excluded });
+    let result = http::backend_json(&app, Method::PATCH, &path, None, Some(payload)).await?;
+    if envelope_succeeded(&result) {
+        app.state::<KnowledgeGraphCache>().stage_excluded(kp_id, excluded);
+    }
+    Ok(result)
 }
 
 #[tauri::command]
@@ -229,38 +162,14 @@ pub async fn add_knowledge_point_keyword(
     kp_id: i32,
     keyword: String,
 ) -> Result<String, String> {
-    let base_url = get_backend_base_url(&app);
-    let base_escaped = base_url.replace('\\', "\\\\").replace('"', "\\\"");
-    let kw_json = serde_json::to_string(&keyword).unwrap();
-    let python_script = format!(
-        r#"
-import sys
-import requests
-import json
-
-base_url = "{}"
-try:
-    response = requests.post(base_url + '/documents/knowledge-points/{}/keywords', json={{ "keyword": {} }})
-    result = {{
-        "success": response.status_code == 200,
-        "data": response.json() if response.status_code == 200 else None,
-        "error": None if response.status_code == 200 else response.text
-    }}
-    print(json.dumps(result))
-except Exception as e:
-    result = {{"success": False, "data": None, "error": str(e)}}
-    print(json.dumps(result))
-"#,
-        base_escaped,
-        kp_id,
-        kw_json
-    );
-    let output = Command::new("python")
-        .arg("-c")
-        .arg(&python_script)
-        .output()
-        .map_err(|e| format!("Failed to execute Python: {}", e))?;
-    Ok(String::from_utf8_lossy(&output.stdout).to_string())
+    use tauri::Manager;
+    let path = format!("/documents/knowledge-points/{}/keywords", kp_id);
+    let payload = serde_json::json!({ "keyword": keyword });
+    let result = http::backend_json(&app, Method::POST, &path, None, Some(payload)).await?;
+    // Keyword edits change the keyword index, not just the row's own fields; invalidate
+    // rather than stage so `by_keyword` can't drift out of sync with the backend.
+    app.state::<KnowledgeGraphCache>().invalidate();
+    Ok(result)
 }
 
 #[tauri::command]
@@ -269,72 +178,24 @@ pub async fn remove_knowledge_point_keyword(
     kp_id: i32,
     keyword: String,
 ) -> Result<String, String> {
-    let base_url = get_backend_base_url(&app);
-    let base_escaped = base_url.replace('\\', "\\\\").replace('"', "\\\"");
-    let kw_json = serde_json::to_string(&keyword).unwrap();
-    let python_script = format!(
-        r#"
-import sys
-import requests
-import json
-
-base_url = "{}"
-try:
-    response = requests.delete(base_url + '/documents/knowledge-points/{}/keywords', json={{ "keyword": {} }})
-    result = {{
-        "success": response.status_code == 200,
-        "data": response.json() if response.status_code == 200 else None,
-        "error": None if response.status_code == 200 else response.text
-    }}
-    print(json.dumps(result))
-except Exception as e:
-    result = {{"success": False, "data": None, "error": str(e)}}
-    print(json.dumps(result))
-"#,
-        base_escaped,
-        kp_id,
-        kw_json
-    );
-    let output = Command::new("python")
-        .arg("-c")
-        .arg(&python_script)
-        .output()
-        .map_err(|e| format!("Failed to execute Python: {}", e))?;
-    Ok(String::from_utf8_lossy(&output.stdout).to_string())
+    use tauri::Manager;
+    let path = format!("/documents/knowledge-points/{}/keywords", kp_id);
+    let payload = serde_json::json!({ "keyword": keyword });
+    let result = http::backend_json(&app, Method::DELETE, &path, None, Some(payload)).await?;
+    app.state::<KnowledgeGraphCache>().invalidate();
+    Ok(result)
 }
 
 #[tauri::command]
-pub async fn get_knowledge_point_keywords(app: tauri::AppHandle, kp_id: i32) -> Result<String, String> {
-    let base_url = get_backend_base_url(&app);
-    let base_escaped = base_url.replace('\\', "\\\\").replace('"', "\\\"");
-    let python_script = format!(
-        r#"
-import sys
-import requests
-import json
+pub async fn get_knowledge_point_keywords(app: tauri::AppHandle, kp_id: i32) -> Result<Vec<Keyword>, BioError> {
+    let path = format!("/documents/knowledge-points/{}/keywords", kp_id);
+    backend_json_typed::<(), Vec<Keyword>>(&app, Method::GET, &path, None, None).await
+}
 
-base_url = "{}"
-try:
-    response = requests.get(base_url + '/documents/knowledge-points/{}/keywords')
-    result = {{
-        "success": response.status_code == 200,
-        "data": response.json() if response.status_code == 200 else None,
-        "error": None if response.status_code == 200 else response.text
-    }}
-    print(json.dumps(result))
-except Exception as e:
-    result = {{"success": False, "data": None, "error": str(e)}}
-    print(json.dumps(result))
-"#,
-        base_escaped,
-        kp_id
-    );
-    let output = Command::new("python")
-        .arg("-c")
-        .arg(&python_script)
-        .output()
-        .map_err(|e| format!("Failed to execute Python: {}", e))?;
-    Ok(String::from_utf8_lossy(&output.stdout).to_string())
+#[derive(serde::Serialize)]
+struct CreateKnowledgePointBody {
+    document_id: i32,
+    content: String,
 }
 
 #[tauri::command]
@@ -342,40 +203,17 @@ pub async fn create_knowledge_point(
     app: tauri::AppHandle,
     document_id: i32,
     content: String,
-) -> Result<String, String> {
-    let base_url = get_backend_base_url(&app);
-    let base_escaped = base_url.replace('\\', "\\\\").replace('"', "\\\"");
-    let payload = serde_json::json!({
-        "document_id": document_id,
-        "content": content
-    });
-    let payload_str = payload.to_string();
-    let python_script = format!(
-        r#"
-import sys
-import requests
-import json
-base_url = "{}"
-try:
-    payload = json.loads(sys.argv[1])
-    response = requests.post(base_url + '/documents/knowledge-points', json=payload)
-    result = {{
-        "success": response.status_code == 200,
-        "data": response.json() if response.status_code == 200 else None,
-        "error": None if response.status_code == 200 else response.text
-    }}
-    print(json.dumps(result))
-except Exception as e:
-    result = {{"success": False, "data": None, "error": str(e)}}
-    print(json.dumps(result))
-"#,
-        base_escaped
-    );
-    let output = Command::new("python")
-        .arg("-c")
-        .arg(&python_script)
-        .arg(&payload_str)
-        .output()
-        .map_err(|e| format!("Failed to execute Python: {}", e))?;
-    Ok(String::from_utf8_lossy(&output.stdout).to_string())
+) -> Result<KnowledgePoint, BioError> {
+    use tauri::Manager;
+    let payload = CreateKnowledgePointBody { document_id, content };
+    let created = backend_json_typed::<CreateKnowledgePointBody, KnowledgePoint>(
+        &app,
+        Method::POST,
+        "/documents/knowledge-points",
+        None,
+        Some(&payload),
+    )
+    .await?;
+    app.state::<KnowledgeGraphCache>().stage_upsert(created.clone());
+    Ok(created)
 }
@@ -1,6 +1,7 @@
 use reqwest::Method;
 
 use crate::commands::http;
+use crate::logging;
 
 #[tauri::command]
 pub async fn get_audit_log(app: tauri::AppHandle, limit: i32) -> Result<String, String> {
@@ -13,3 +14,10 @@ pub async fn get_desensitization_log(app: tauri::AppHandle, limit: i32) -> Resul
     let query = vec![("limit".to_string(), limit.to_string())];
     http::backend_json(&app, Method::GET, "/desensitization-log", Some(query), None).await
 }
+
+/// Tails this app instance's own tracing log file (not the backend's) so a user can copy
+/// diagnostics straight into a bug report without digging through the filesystem.
+#[tauri::command]
+pub fn get_backend_logs(app: tauri::AppHandle, max_lines: Option<usize>) -> Result<String, String> {
+    Ok(logging::tail_today(&app, max_lines.unwrap_or(500)))
+}
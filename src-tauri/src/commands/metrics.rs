@@ -0,0 +1,35 @@
+use std::sync::OnceLock;
+
+use metrics_exporter_prometheus::{PrometheusBuilder, PrometheusHandle};
+
+static PROMETHEUS_HANDLE: OnceLock<PrometheusHandle> = OnceLock::new();
+
+fn metrics_enabled() -> bool {
+    std::env::var("BIOFORGER_METRICS_ENABLED")
+        .map(|v| v != "0")
+        .unwrap_or(true)
+}
+
+/// Installs the Prometheus recorder once at startup, gated by `BIOFORGER_METRICS_ENABLED`
+/// (set to "0" to disable). Safe to call more than once; only the first call installs it.
+pub fn init_metrics() {
+    if !metrics_enabled() {
+        return;
+    }
+    if PROMETHEUS_HANDLE.get().is_some() {
+        return;
+    }
+    if let Ok(handle) = PrometheusBuilder::new().install_recorder() {
+        let _ = PROMETHEUS_HANDLE.set(handle);
+    }
+}
+
+/// Dumps the current metrics snapshot in Prometheus text exposition format, for an in-app
+/// diagnostics panel. Returns an empty string if metrics are disabled or not yet installed.
+#[tauri::command]
+pub fn get_metrics_snapshot() -> Result<String, String> {
+    Ok(PROMETHEUS_HANDLE
+        .get()
+        .map(|h| h.render())
+        .unwrap_or_default())
+}
@@ -92,16 +92,19 @@ pub async fn get_local_models(app: tauri::AppHandle, base_url: Option<String>) -
         .send()
         .await
         .map_err(|e| format!("Backend request failed: {}", e))?;
-    let status_ok = resp.status().as_u16() == 200;
+    let status = resp.status();
     let bytes = resp
         .bytes()
         .await
         .map_err(|e| format!("Failed to read response: {}", e))?;
-    if status_ok {
+    if status.is_success() {
         let data = serde_json::from_slice::<serde_json::Value>(&bytes).unwrap_or(serde_json::Value::Null);
         http::json_result(true, data, None)
     } else {
-        let error = String::from_utf8_lossy(&bytes).to_string();
-        http::json_result(false, serde_json::Value::Null, Some(error))
+        http::json_result(
+            false,
+            serde_json::Value::Null,
+            Some(http::BioError::endpoint(status, &bytes)),
+        )
     }
 }
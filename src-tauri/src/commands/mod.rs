@@ -0,0 +1,33 @@
+pub mod annotations;
+pub mod api_keys;
+pub mod backend_lifecycle;
+pub mod backend_preflight;
+pub mod causal_poll;
+pub mod chat;
+pub mod chat_history;
+pub mod config;
+pub mod directories;
+pub mod documents;
+pub mod evaluation;
+pub mod features;
+pub mod finetuning;
+pub mod http;
+pub mod kg_cache;
+pub mod knowledge_points;
+pub mod logs;
+pub mod metrics;
+pub mod misc;
+pub mod models;
+pub mod mount_points;
+pub mod mount_watcher;
+pub mod ollama;
+pub mod preview_blurhash;
+pub mod preview_cache;
+pub mod rules;
+pub mod search;
+pub mod secrets;
+pub mod skills;
+pub mod storage;
+pub mod training;
+pub mod typed_client;
+pub mod workspace_dump;
@@ -0,0 +1,58 @@
+//! Hand-written typed models for the endpoints whose shape is unambiguous from how the
+//! existing commands already use them (`weight`, `excluded`, `min_weight`, `document_id`,
+//! `content` all appear as literal field names in the knowledge-point commands). Unlike
+//! `typed_client`'s generic plumbing, these are real per-entity structs so a small, known
+//! slice of the API gets compile-time field checking instead of opaque `serde_json::Value`.
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Keyword(pub String);
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KnowledgePoint {
+    pub id: i32,
+    pub document_id: i32,
+    pub content: String,
+    pub weight: f64,
+    pub excluded: bool,
+    #[serde(default)]
+    pub keywords: Vec<Keyword>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KnowledgePointPage {
+    pub items: Vec<KnowledgePoint>,
+    pub page: i32,
+    pub page_size: i32,
+    pub total: i32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Document {
+    pub id: i32,
+    pub filename: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DocumentSummary {
+    pub document_id: i32,
+    pub summary: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FinetuningJob {
+    pub id: String,
+    pub status: String,
+    #[serde(default)]
+    pub progress: Option<f64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Annotation {
+    pub knowledge_point: String,
+    pub content: String,
+    #[serde(default)]
+    pub candidate_index: Option<i32>,
+}
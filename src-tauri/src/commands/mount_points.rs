@@ -1,8 +1,22 @@
 use base64::Engine;
 use reqwest::Method;
+use serde::Deserialize;
+use tauri::Manager;
 
 use crate::backend_url::get_backend_base_url;
+use crate::commands::features::load_runtime_features;
 use crate::commands::http;
+use crate::commands::models::DocumentSummary;
+use crate::commands::preview_blurhash;
+use crate::commands::preview_cache::PreviewCache;
+use crate::commands::typed_client::backend_json_typed;
+
+#[derive(Deserialize)]
+pub struct FileMetaBatchItem {
+    relative_path: String,
+    weight: Option<f64>,
+    note: Option<String>,
+}
 
 #[tauri::command]
 pub async fn get_mount_points(app: tauri::AppHandle) -> Result<String, String> {
@@ -90,6 +104,29 @@ pub async fn update_mount_point_file_meta(
     http::backend_json(&app, Method::PATCH, &path, None, Some(serde_json::Value::Object(payload))).await
 }
 
+/// Batch variant of `update_mount_point_file_meta`: one request for "select N files, set
+/// weight/note" instead of one round-trip per file. `items` mirrors the single-item shape.
+#[tauri::command]
+pub async fn update_mount_point_files_meta_batch(
+    app: tauri::AppHandle,
+    mp_id: i32,
+    items: Vec<FileMetaBatchItem>,
+) -> Result<String, String> {
+    let items: Vec<serde_json::Value> = items
+        .into_iter()
+        .map(|item| {
+            serde_json::json!({
+                "relative_path": item.relative_path,
+                "weight": item.weight,
+                "note": item.note,
+            })
+        })
+        .collect();
+    let payload = serde_json::json!({ "items": items });
+    let path = format!("/mount-points/{}/files/meta/batch", mp_id);
+    http::backend_json(&app, Method::PATCH, &path, None, Some(payload)).await
+}
+
 #[tauri::command]
 pub async fn get_document_summary(
     app: tauri::AppHandle,
@@ -100,34 +137,72 @@ pub async fn get_document_summary(
         ("mp_id".to_string(), mp_id.to_string()),
         ("relative_path".to_string(), relative_path),
     ];
-    http::backend_json(&app, Method::GET, "/mount-points/document-summary", Some(query), None).await
+    match backend_json_typed::<(), DocumentSummary>(&app, Method::GET, "/mount-points/document-summary", Some(query), None).await {
+        Ok(summary) => http::json_result(true, serde_json::to_value(summary).unwrap_or_default(), None),
+        Err(error) => http::json_result(false, serde_json::Value::Null, Some(error)),
+    }
 }
 
+/// Serves a cached preview when the backend reports the same `X-Preview-Version` it handed
+/// out last time, so re-opening a document doesn't re-transfer and re-base64-encode it.
 #[tauri::command]
 pub async fn get_document_preview(
     app: tauri::AppHandle,
     mp_id: i32,
     relative_path: String,
+    blurhash_x_components: Option<u32>,
+    blurhash_y_components: Option<u32>,
 ) -> Result<String, String> {
+    let cache = app.try_state::<PreviewCache>();
+    let known_version = cache.as_ref().and_then(|c| c.known_version(mp_id, &relative_path));
+
     let base_url = get_backend_base_url(&app);
     let url = format!("{}/mount-points/document-preview", base_url);
     let client = reqwest::Client::new();
+    let mut query = vec![
+        ("mp_id".to_string(), mp_id.to_string()),
+        ("relative_path".to_string(), relative_path.clone()),
+    ];
+    if let Some(ref v) = known_version {
+        query.push(("known_version".to_string(), v.clone()));
+    }
+    // Experimental: asks the backend to run OCR over scanned/image documents while the
+    // feature is still behind a flag.
+    if load_runtime_features(&app).local_ocr {
+        query.push(("ocr".to_string(), "true".to_string()));
+    }
     let resp = client
         .get(&url)
-        .query(&[
-            ("mp_id".to_string(), mp_id.to_string()),
-            ("relative_path".to_string(), relative_path),
-        ])
+        .query(&query)
         .send()
         .await
         .map_err(|e| format!("Backend request failed: {}", e))?;
-    let status_ok = resp.status().as_u16() == 200;
-    if !status_ok {
-        let error = resp
-            .text()
-            .await
-            .unwrap_or_else(|e| format!("Failed to read error body: {}", e));
-        return http::json_result(false, serde_json::Value::Null, Some(error));
+    let status = resp.status();
+
+    if status.as_u16() == 304 {
+        let cached_bytes = known_version
+            .as_deref()
+            .zip(cache.as_ref())
+            .and_then(|(v, c)| c.lookup(mp_id, &relative_path, v));
+        if let (Some(version), Some(bytes)) = (known_version.clone(), cached_bytes) {
+            let encoded = base64::engine::general_purpose::STANDARD.encode(bytes);
+            return serde_json::to_string(&serde_json::json!({
+                "success": true,
+                "data": encoded,
+                "version": version,
+                "error": null
+            }))
+            .map_err(|e| e.to_string());
+        }
+    }
+
+    if !status.is_success() {
+        let body = resp.bytes().await.unwrap_or_default();
+        return http::json_result(
+            false,
+            serde_json::Value::Null,
+            Some(http::BioError::endpoint(status, &body)),
+        );
     }
     let version = resp
         .headers()
@@ -136,17 +211,41 @@ pub async fn get_document_preview(
         .and_then(|v| v.to_str().ok())
         .unwrap_or("")
         .to_string();
+    let content_type = resp
+        .headers()
+        .get("Content-Type")
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or("")
+        .to_string();
     let bytes = resp
         .bytes()
         .await
         .map_err(|e| format!("Failed to read response bytes: {}", e))?;
-    let encoded = base64::engine::general_purpose::STANDARD.encode(bytes);
-    serde_json::to_string(&serde_json::json!({
+
+    if !version.is_empty() {
+        if let Some(c) = cache.as_ref() {
+            c.store(mp_id, &relative_path, &version, &bytes);
+        }
+    }
+
+    let blurhash = preview_blurhash::encode_if_image(
+        &bytes,
+        &content_type,
+        blurhash_x_components,
+        blurhash_y_components,
+    );
+
+    let encoded = base64::engine::general_purpose::STANDARD.encode(&bytes);
+    let mut response = serde_json::json!({
         "success": true,
         "data": encoded,
         "version": version,
         "error": null
-    }))
+    });
+    if let Some(hash) = blurhash {
+        response["blurhash"] = serde_json::json!(hash);
+    }
+    serde_json::to_string(&response)
     .map_err(|e| e.to_string())
 }
 
@@ -0,0 +1,133 @@
+// Live filesystem watcher for mount points, so the UI can react to files changing on disk
+// instead of re-polling `get_mount_point_files`/`get_recent_annotated_files`. Built on
+// `notify` the same way `ChatStreamRegistry` tracks in-flight streams: a cancel flag per
+// watched mount, checked by the background flush task. `unwatch_mount_point` drops the
+// `notify` watcher itself right away; the flush task stops at its next tick.
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use notify::{Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+use serde::Serialize;
+use tauri::Emitter;
+
+/// Rapid bursts (editors writing temp files, multi-file saves) within this window collapse
+/// into a single emitted event per path instead of one per underlying fs event.
+const DEBOUNCE_WINDOW: Duration = Duration::from_millis(400);
+const FLUSH_TICK: Duration = Duration::from_millis(150);
+
+#[derive(Serialize, Clone)]
+struct MountChange {
+    mp_id: i32,
+    relative_path: String,
+    kind: &'static str,
+}
+
+struct WatchedMount {
+    _watcher: RecommendedWatcher,
+    stop: Arc<AtomicBool>,
+}
+
+/// Tauri-managed registry of active per-mount-point watchers, keyed by `mp_id`.
+#[derive(Default)]
+pub struct MountWatcherRegistry {
+    watched: Mutex<HashMap<i32, WatchedMount>>,
+}
+
+fn event_kind_label(kind: &EventKind) -> Option<&'static str> {
+    match kind {
+        EventKind::Create(_) => Some("create"),
+        EventKind::Modify(_) => Some("modify"),
+        EventKind::Remove(_) => Some("delete"),
+        _ => None,
+    }
+}
+
+fn relative_path_of(root: &Path, p: &Path) -> Option<String> {
+    p.strip_prefix(root).ok().map(|rel| rel.to_string_lossy().replace('\\', "/"))
+}
+
+/// Reuses `list_system_dir`'s dotfile-skipping rule so editor swap/lock files don't spam
+/// the UI with change events.
+fn is_dotfile(relative_path: &str) -> bool {
+    relative_path.split('/').any(|segment| segment.starts_with('.'))
+}
+
+/// Starts watching `path` (the mount point's root directory) for create/modify/delete
+/// events and emits debounced `mount://changed` events carrying `{mp_id, relative_path,
+/// kind}`. A no-op if `mp_id` is already being watched.
+#[tauri::command]
+pub fn watch_mount_point(
+    app: tauri::AppHandle,
+    registry: tauri::State<'_, MountWatcherRegistry>,
+    mp_id: i32,
+    path: String,
+) -> Result<(), String> {
+    let mut watched = registry.watched.lock().unwrap();
+    if watched.contains_key(&mp_id) {
+        return Ok(());
+    }
+
+    let root: PathBuf = PathBuf::from(&path);
+    let pending: Arc<Mutex<HashMap<String, (&'static str, Instant)>>> = Arc::new(Mutex::new(HashMap::new()));
+    let stop = Arc::new(AtomicBool::new(false));
+
+    let pending_for_events = pending.clone();
+    let root_for_events = root.clone();
+    let mut watcher = notify::recommended_watcher(move |res: notify::Result<Event>| {
+        let Ok(event) = res else { return };
+        let Some(kind) = event_kind_label(&event.kind) else { return };
+        let mut pending = pending_for_events.lock().unwrap();
+        for changed_path in &event.paths {
+            let Some(relative_path) = relative_path_of(&root_for_events, changed_path) else { continue };
+            if is_dotfile(&relative_path) {
+                continue;
+            }
+            pending.insert(relative_path, (kind, Instant::now()));
+        }
+    })
+    .map_err(|e| e.to_string())?;
+
+    watcher.watch(&root, RecursiveMode::Recursive).map_err(|e| e.to_string())?;
+
+    let stop_for_flush = stop.clone();
+    tauri::async_runtime::spawn(async move {
+        loop {
+            tokio::time::sleep(FLUSH_TICK).await;
+            if stop_for_flush.load(Ordering::Relaxed) {
+                return;
+            }
+
+            let ready: Vec<(String, &'static str)> = {
+                let mut map = pending.lock().unwrap();
+                let now = Instant::now();
+                let ready_keys: Vec<String> = map
+                    .iter()
+                    .filter(|(_, (_, seen_at))| now.duration_since(*seen_at) >= DEBOUNCE_WINDOW)
+                    .map(|(relative_path, _)| relative_path.clone())
+                    .collect();
+                ready_keys
+                    .into_iter()
+                    .filter_map(|relative_path| map.remove(&relative_path).map(|(kind, _)| (relative_path, kind)))
+                    .collect()
+            };
+            for (relative_path, kind) in ready {
+                let _ = app.emit("mount://changed", MountChange { mp_id, relative_path, kind });
+            }
+        }
+    });
+
+    watched.insert(mp_id, WatchedMount { _watcher: watcher, stop });
+    Ok(())
+}
+
+/// Stops watching `mp_id`. A no-op if it wasn't being watched.
+#[tauri::command]
+pub fn unwatch_mount_point(registry: tauri::State<'_, MountWatcherRegistry>, mp_id: i32) -> Result<(), String> {
+    if let Some(watched) = registry.watched.lock().unwrap().remove(&mp_id) {
+        watched.stop.store(true, Ordering::Relaxed);
+    }
+    Ok(())
+}
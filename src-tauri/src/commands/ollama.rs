@@ -1,11 +1,87 @@
+use std::collections::VecDeque;
 use std::process::Command;
+use std::sync::Mutex;
 use std::time::Duration;
 
-use crate::commands::backend_lifecycle::kill_process_on_port;
-use crate::state::OllamaState;
+#[cfg(not(windows))]
+use std::os::unix::process::CommandExt as _;
+
+use serde::{Deserialize, Serialize};
+use tauri::Emitter;
+
+use crate::commands::backend_lifecycle::{emit_terminated, kill_process_on_port, spawn_output_reader, ProcessLogSink};
+use crate::state::{OllamaProcess, OllamaState, OllamaSupervisorState, SupervisedPhase, SupervisorSnapshot};
 
 pub const OLLAMA_PORT: u16 = 11434;
 
+const SUPERVISOR_POLL_INTERVAL: Duration = Duration::from_secs(5);
+const SUPERVISOR_FAILURE_THRESHOLD: u32 = 3;
+const OLLAMA_LOG_CAPACITY: usize = 500;
+
+/// Mirrors `backend_lifecycle::BackendLogBuffer` for the Ollama sidecar's stdout/stderr, so an
+/// in-app console can show Ollama's own output the same way it shows the Python backend's.
+#[derive(Default)]
+pub struct OllamaLogBuffer(Mutex<VecDeque<String>>);
+
+impl OllamaLogBuffer {
+    fn push(&self, line: String) {
+        if let Ok(mut buf) = self.0.lock() {
+            buf.push_back(line);
+            if buf.len() > OLLAMA_LOG_CAPACITY {
+                buf.pop_front();
+            }
+        }
+    }
+
+    fn snapshot(&self) -> Vec<String> {
+        self.0.lock().map(|buf| buf.iter().cloned().collect()).unwrap_or_default()
+    }
+}
+
+impl ProcessLogSink for OllamaLogBuffer {
+    fn push_line(&self, line: String) {
+        self.push(line);
+    }
+}
+
+/// Recent Ollama stdout/stderr lines from the in-memory ring buffer, mirroring
+/// `backend_lifecycle::get_backend_process_logs`.
+#[tauri::command]
+pub fn get_ollama_process_logs(buffer: tauri::State<'_, OllamaLogBuffer>) -> Result<Vec<String>, String> {
+    Ok(buffer.snapshot())
+}
+
+/// Spawns `ollama serve` with piped stdio, wiring up its output readers the same way on both
+/// the initial start and every supervisor restart. On Unix the child is made its own session
+/// leader via `setsid` so `OllamaProcess`'s `Drop` impl can kill the whole group.
+fn spawn_ollama_serve(app: tauri::AppHandle) -> std::io::Result<OllamaProcess> {
+    let mut cmd = Command::new("ollama");
+    cmd.arg("serve")
+        .stdout(std::process::Stdio::piped())
+        .stderr(std::process::Stdio::piped());
+    #[cfg(not(windows))]
+    unsafe {
+        cmd.pre_exec(|| {
+            libc::setsid();
+            Ok(())
+        });
+    }
+    let mut child = cmd.spawn()?;
+    #[cfg(not(windows))]
+    let pgid = Some(child.id() as i32);
+    if let Some(stdout) = child.stdout.take() {
+        spawn_output_reader::<OllamaLogBuffer>(Some(app.clone()), "ollama://stdout", "stdout", stdout);
+    }
+    if let Some(stderr) = child.stderr.take() {
+        spawn_output_reader::<OllamaLogBuffer>(Some(app), "ollama://stderr", "stderr", stderr);
+    }
+    Ok(OllamaProcess {
+        child,
+        #[cfg(not(windows))]
+        pgid,
+    })
+}
+
 pub fn is_ollama_running() -> bool {
     let url = format!("http://127.0.0.1:{}/api/tags", OLLAMA_PORT);
     let client = reqwest::Client::builder()
@@ -23,18 +99,13 @@ pub fn is_ollama_running() -> bool {
 }
 
 #[tauri::command]
-pub async fn start_ollama(state: tauri::State<'_, OllamaState>) -> Result<String, String> {
+pub async fn start_ollama(app: tauri::AppHandle, state: tauri::State<'_, OllamaState>) -> Result<String, String> {
     if is_ollama_running() {
         return Ok("OLLAMA already running".to_string());
     }
-    let child = Command::new("ollama")
-        .arg("serve")
-        .stdout(std::process::Stdio::null())
-        .stderr(std::process::Stdio::null())
-        .spawn()
-        .map_err(|e| format!("Failed to start OLLAMA: {}", e))?;
+    let proc_ = spawn_ollama_serve(app).map_err(|e| format!("Failed to start OLLAMA: {}", e))?;
     if let Ok(mut guard) = state.process.lock() {
-        *guard = Some(child);
+        *guard = Some(proc_);
     }
     Ok("OLLAMA started".to_string())
 }
@@ -47,3 +118,265 @@ pub async fn stop_ollama(state: tauri::State<'_, OllamaState>) -> Result<String,
     kill_process_on_port(OLLAMA_PORT);
     Ok("OLLAMA stopped".to_string())
 }
+
+fn set_ollama_phase(app: &tauri::AppHandle, phase: SupervisedPhase, pid: Option<u32>) {
+    use tauri::Manager;
+    if let Some(supervisor) = app.try_state::<OllamaSupervisorState>() {
+        if let Ok(mut snapshot) = supervisor.snapshot.lock() {
+            snapshot.phase = phase;
+            if pid.is_some() {
+                snapshot.pid = pid;
+            }
+            if phase == SupervisedPhase::Ready {
+                snapshot.last_healthy_unix_ms = std::time::SystemTime::now()
+                    .duration_since(std::time::UNIX_EPOCH)
+                    .ok()
+                    .map(|d| d.as_millis() as u64);
+                snapshot.last_error = None;
+            }
+            if phase == SupervisedPhase::Restarting {
+                snapshot.restarts += 1;
+            }
+            if phase == SupervisedPhase::Stopped {
+                snapshot.pid = None;
+            }
+            let _ = app.emit("ollama-status", snapshot.clone());
+        }
+    }
+}
+
+/// Records the reason behind a restart failure, mirroring `backend_lifecycle::set_supervisor_error`.
+fn set_ollama_error(app: &tauri::AppHandle, message: impl Into<String>) {
+    use tauri::Manager;
+    if let Some(supervisor) = app.try_state::<OllamaSupervisorState>() {
+        if let Ok(mut snapshot) = supervisor.snapshot.lock() {
+            snapshot.last_error = Some(message.into());
+            let _ = app.emit("ollama-status", snapshot.clone());
+        }
+    }
+}
+
+/// Mirrors `backend_lifecycle::run_backend_supervisor` for the Ollama sidecar: only restarts
+/// when `OllamaState` already holds a child we spawned, so the user's own externally-started
+/// `ollama serve` is left alone.
+pub async fn run_ollama_supervisor(app: tauri::AppHandle) {
+    use tauri::Manager;
+
+    let mut consecutive_failures: u32 = 0;
+    loop {
+        tokio::time::sleep(SUPERVISOR_POLL_INTERVAL).await;
+
+        let we_manage_it = app
+            .try_state::<OllamaState>()
+            .map(|s| s.process.lock().map(|g| g.is_some()).unwrap_or(false))
+            .unwrap_or(false);
+        if !we_manage_it {
+            continue;
+        }
+
+        if is_ollama_running() {
+            consecutive_failures = 0;
+            set_ollama_phase(&app, SupervisedPhase::Ready, None);
+            continue;
+        }
+
+        consecutive_failures += 1;
+        if consecutive_failures < SUPERVISOR_FAILURE_THRESHOLD {
+            set_ollama_phase(&app, SupervisedPhase::Degraded, None);
+            continue;
+        }
+
+        tracing::warn!(consecutive_failures, "ollama unhealthy, restarting");
+        set_ollama_phase(&app, SupervisedPhase::Restarting, None);
+        if let Some(state) = app.try_state::<OllamaState>() {
+            if let Ok(mut guard) = state.process.lock() {
+                if let Some(mut proc_) = guard.take() {
+                    emit_terminated(&app, "ollama://terminated", &mut proc_.child);
+                    let _ = proc_.child.kill();
+                }
+            }
+        }
+        kill_process_on_port(OLLAMA_PORT);
+
+        match spawn_ollama_serve(app.clone()) {
+            Ok(proc_) => {
+                let pid = proc_.child.id();
+                if let Some(state) = app.try_state::<OllamaState>() {
+                    if let Ok(mut guard) = state.process.lock() {
+                        *guard = Some(proc_);
+                    }
+                }
+                consecutive_failures = 0;
+                set_ollama_phase(&app, SupervisedPhase::Ready, Some(pid));
+            }
+            Err(e) => {
+                tracing::error!(error = %e, "failed to restart ollama");
+                set_ollama_phase(&app, SupervisedPhase::Degraded, None);
+                set_ollama_error(&app, e.to_string());
+            }
+        }
+    }
+}
+
+#[tauri::command]
+pub fn get_ollama_status(
+    supervisor: tauri::State<'_, OllamaSupervisorState>,
+) -> Result<SupervisorSnapshot, String> {
+    supervisor
+        .snapshot
+        .lock()
+        .map(|s| s.clone())
+        .map_err(|_| "Ollama status lock poisoned".to_string())
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OllamaModel {
+    pub name: String,
+    pub size: u64,
+    pub modified_at: String,
+}
+
+#[derive(Deserialize)]
+struct OllamaTagsResponse {
+    #[serde(default)]
+    models: Vec<OllamaTagsModel>,
+}
+
+#[derive(Deserialize)]
+struct OllamaTagsModel {
+    name: String,
+    #[serde(default)]
+    size: u64,
+    #[serde(default)]
+    modified_at: String,
+}
+
+fn ollama_client() -> reqwest::Client {
+    reqwest::Client::builder()
+        .timeout(Duration::from_secs(10))
+        .build()
+        .unwrap_or_else(|_| reqwest::Client::new())
+}
+
+/// Lists locally pulled models, mirroring `is_ollama_running`'s own ping of the same endpoint.
+#[tauri::command]
+pub async fn list_ollama_models() -> Result<Vec<OllamaModel>, String> {
+    let url = format!("http://127.0.0.1:{}/api/tags", OLLAMA_PORT);
+    let resp = ollama_client()
+        .get(&url)
+        .send()
+        .await
+        .map_err(|e| format!("Failed to reach OLLAMA: {}", e))?;
+    if !resp.status().is_success() {
+        return Err(format!("OLLAMA returned status {}", resp.status()));
+    }
+    let parsed: OllamaTagsResponse = resp
+        .json()
+        .await
+        .map_err(|e| format!("Failed to parse OLLAMA model list: {}", e))?;
+    Ok(parsed
+        .models
+        .into_iter()
+        .map(|m| OllamaModel {
+            name: m.name,
+            size: m.size,
+            modified_at: m.modified_at,
+        })
+        .collect())
+}
+
+#[tauri::command]
+pub async fn delete_ollama_model(name: String) -> Result<String, String> {
+    let url = format!("http://127.0.0.1:{}/api/delete", OLLAMA_PORT);
+    let resp = ollama_client()
+        .delete(&url)
+        .json(&serde_json::json!({ "name": name }))
+        .send()
+        .await
+        .map_err(|e| format!("Failed to reach OLLAMA: {}", e))?;
+    if !resp.status().is_success() {
+        let body = resp.text().await.unwrap_or_default();
+        return Err(format!("OLLAMA failed to delete model: {}", body));
+    }
+    Ok(format!("Deleted model {}", name))
+}
+
+#[derive(Serialize, Clone)]
+struct OllamaPullProgress<'a> {
+    model: &'a str,
+    status: &'a str,
+    digest: Option<&'a str>,
+    total: Option<u64>,
+    completed: Option<u64>,
+    error: Option<&'a str>,
+}
+
+/// POSTs to `/api/pull` and relays the daemon's newline-delimited JSON stream as
+/// `ollama://pull-progress` events, one per chunk, so the frontend can render a download bar
+/// without polling. Each line is a standalone JSON object; the final one carries
+/// `status: "success"` or an `error` field.
+#[tauri::command]
+pub async fn pull_ollama_model(app: tauri::AppHandle, name: String) -> Result<String, String> {
+    let url = format!("http://127.0.0.1:{}/api/pull", OLLAMA_PORT);
+    let client = reqwest::Client::new();
+    let mut resp = client
+        .post(&url)
+        .json(&serde_json::json!({ "name": name, "stream": true }))
+        .send()
+        .await
+        .map_err(|e| format!("Failed to reach OLLAMA: {}", e))?;
+
+    if !resp.status().is_success() {
+        let body = resp.text().await.unwrap_or_default();
+        return Err(format!("OLLAMA failed to start pull: {}", body));
+    }
+
+    let mut buf = String::new();
+    let mut final_error: Option<String> = None;
+    let mut succeeded = false;
+
+    while let Some(chunk) = resp
+        .chunk()
+        .await
+        .map_err(|e| format!("Pull stream read failed: {}", e))?
+    {
+        buf.push_str(&String::from_utf8_lossy(&chunk));
+        while let Some(newline) = buf.find('\n') {
+            let line = buf[..newline].trim().to_string();
+            buf.drain(..=newline);
+            if line.is_empty() {
+                continue;
+            }
+            let Ok(parsed) = serde_json::from_str::<serde_json::Value>(&line) else {
+                continue;
+            };
+            let status = parsed.get("status").and_then(|v| v.as_str()).unwrap_or("");
+            let error = parsed.get("error").and_then(|v| v.as_str());
+            let _ = app.emit(
+                "ollama://pull-progress",
+                OllamaPullProgress {
+                    model: &name,
+                    status,
+                    digest: parsed.get("digest").and_then(|v| v.as_str()),
+                    total: parsed.get("total").and_then(|v| v.as_u64()),
+                    completed: parsed.get("completed").and_then(|v| v.as_u64()),
+                    error,
+                },
+            );
+            if let Some(e) = error {
+                final_error = Some(e.to_string());
+            }
+            if status == "success" {
+                succeeded = true;
+            }
+        }
+    }
+
+    if let Some(e) = final_error {
+        return Err(format!("OLLAMA failed to pull model {}: {}", name, e));
+    }
+    if !succeeded {
+        return Err(format!("OLLAMA pull stream for {} ended without a success status", name));
+    }
+    Ok(format!("Pulled model {}", name))
+}
@@ -0,0 +1,30 @@
+// Blurhash placeholders for image previews: a compact ~20-30 char token the frontend can
+// render instantly while the full base64 payload is still decoding.
+const DEFAULT_X_COMPONENTS: u32 = 4;
+const DEFAULT_Y_COMPONENTS: u32 = 3;
+const DOWNSCALE_MAX_DIM: u32 = 64;
+
+/// Returns a blurhash string for `bytes` if `content_type` indicates an image, `None`
+/// otherwise (including on any decode failure, or for a non-image payload like a PDF's raw
+/// bytes — this is a best-effort enhancement, not a required part of the preview response).
+pub fn encode_if_image(
+    bytes: &[u8],
+    content_type: &str,
+    x_components: Option<u32>,
+    y_components: Option<u32>,
+) -> Option<String> {
+    if !content_type.starts_with("image/") {
+        return None;
+    }
+    let img = image::load_from_memory(bytes).ok()?;
+    let small = img.thumbnail(DOWNSCALE_MAX_DIM, DOWNSCALE_MAX_DIM).to_rgba8();
+    let (width, height) = small.dimensions();
+    blurhash::encode(
+        x_components.unwrap_or(DEFAULT_X_COMPONENTS),
+        y_components.unwrap_or(DEFAULT_Y_COMPONENTS),
+        width,
+        height,
+        small.as_raw(),
+    )
+    .ok()
+}
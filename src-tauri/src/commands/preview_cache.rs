@@ -0,0 +1,172 @@
+// On-disk cache for document previews. The index is keyed by (mp_id, relative_path) and
+// revalidated against the backend's X-Preview-Version header so an unchanged document is
+// served from disk instead of re-downloaded and re-base64-encoded on every call; the blob
+// itself is stored under a SHA-256 digest of (mp_id, relative_path, version), so it's
+// addressed by exactly what determines its content rather than just the path.
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use tauri::Manager;
+
+const DEFAULT_MAX_BYTES: u64 = 200 * 1024 * 1024;
+const INDEX_FILENAME: &str = "preview-cache-index.json";
+
+#[derive(Serialize, Deserialize, Clone)]
+struct CacheEntry {
+    version: String,
+    bytes_len: u64,
+    file_name: String,
+    last_used_unix_ms: u64,
+}
+
+#[derive(Default, Serialize, Deserialize)]
+struct CacheIndex {
+    entries: HashMap<String, CacheEntry>,
+}
+
+pub struct PreviewCache {
+    dir: PathBuf,
+    max_bytes: u64,
+    index: Mutex<CacheIndex>,
+}
+
+fn cache_key(mp_id: i32, relative_path: &str) -> String {
+    format!("{}:{}", mp_id, relative_path)
+}
+
+/// Content-addressed file name: a SHA-256 digest of `{mp_id, relative_path, version}`, so
+/// the on-disk blob is keyed by exactly the inputs that determine its content rather than
+/// just the path. A version bump (or rollback) lands under a distinct file instead of
+/// overwriting the previous one in place.
+fn content_address(mp_id: i32, relative_path: &str, version: &str) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(mp_id.to_le_bytes());
+    hasher.update(b":");
+    hasher.update(relative_path.as_bytes());
+    hasher.update(b":");
+    hasher.update(version.as_bytes());
+    format!("{:x}", hasher.finalize())
+}
+
+fn now_ms() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or(0)
+}
+
+impl PreviewCache {
+    pub fn init(app: &tauri::AppHandle) -> Option<Self> {
+        let dir = app.path().app_cache_dir().ok()?.join("previews");
+        std::fs::create_dir_all(&dir).ok()?;
+        let index = std::fs::read_to_string(dir.join(INDEX_FILENAME))
+            .ok()
+            .and_then(|s| serde_json::from_str(&s).ok())
+            .unwrap_or_default();
+        Some(PreviewCache {
+            dir,
+            max_bytes: DEFAULT_MAX_BYTES,
+            index: Mutex::new(index),
+        })
+    }
+
+    fn persist_index(&self, index: &CacheIndex) {
+        if let Ok(json) = serde_json::to_string(index) {
+            let _ = std::fs::write(self.dir.join(INDEX_FILENAME), json);
+        }
+    }
+
+    /// Returns the cached bytes for `(mp_id, relative_path)` if present and at `known_version`.
+    pub fn lookup(&self, mp_id: i32, relative_path: &str, known_version: &str) -> Option<Vec<u8>> {
+        let key = cache_key(mp_id, relative_path);
+        let mut index = self.index.lock().ok()?;
+        let entry = index.entries.get(&key)?.clone();
+        if entry.version != known_version {
+            return None;
+        }
+        let bytes = std::fs::read(self.dir.join(&entry.file_name)).ok()?;
+        if let Some(e) = index.entries.get_mut(&key) {
+            e.last_used_unix_ms = now_ms();
+        }
+        self.persist_index(&index);
+        Some(bytes)
+    }
+
+    /// Returns the version currently on disk for `(mp_id, relative_path)`, used to build a
+    /// conditional request without reading the (possibly large) cached payload.
+    pub fn known_version(&self, mp_id: i32, relative_path: &str) -> Option<String> {
+        let key = cache_key(mp_id, relative_path);
+        self.index.lock().ok()?.entries.get(&key).map(|e| e.version.clone())
+    }
+
+    /// Stores freshly-downloaded bytes under `(mp_id, relative_path)` and evicts
+    /// least-recently-used entries until the cache is back under `max_bytes`.
+    pub fn store(&self, mp_id: i32, relative_path: &str, version: &str, bytes: &[u8]) {
+        let key = cache_key(mp_id, relative_path);
+        let file_name = format!("{}.bin", content_address(mp_id, relative_path, version));
+        if std::fs::write(self.dir.join(&file_name), bytes).is_err() {
+            return;
+        }
+        let Ok(mut index) = self.index.lock() else { return };
+        let previous = index.entries.insert(
+            key,
+            CacheEntry {
+                version: version.to_string(),
+                bytes_len: bytes.len() as u64,
+                file_name: file_name.clone(),
+                last_used_unix_ms: now_ms(),
+            },
+        );
+        // Two concurrent stores for the same (mp_id, relative_path, version) content-address
+        // to the same file; only remove the previous blob when it's a distinct file; otherwise
+        // this would delete the file this very call just wrote.
+        if let Some(previous) = previous {
+            if previous.file_name != file_name {
+                let _ = std::fs::remove_file(self.dir.join(&previous.file_name));
+            }
+        }
+        self.evict_if_needed(&mut index);
+        self.persist_index(&index);
+    }
+
+    fn evict_if_needed(&self, index: &mut CacheIndex) {
+        let mut total: u64 = index.entries.values().map(|e| e.bytes_len).sum();
+        if total <= self.max_bytes {
+            return;
+        }
+        let mut by_age: Vec<(String, u64, u64)> = index
+            .entries
+            .iter()
+            .map(|(k, e)| (k.clone(), e.last_used_unix_ms, e.bytes_len))
+            .collect();
+        by_age.sort_by_key(|(_, last_used, _)| *last_used);
+        for (key, _, bytes_len) in by_age {
+            if total <= self.max_bytes {
+                break;
+            }
+            if let Some(entry) = index.entries.remove(&key) {
+                let _ = std::fs::remove_file(self.dir.join(&entry.file_name));
+                total = total.saturating_sub(bytes_len);
+            }
+        }
+    }
+
+    pub fn clear(&self) {
+        if let Ok(mut index) = self.index.lock() {
+            for entry in index.entries.values() {
+                let _ = std::fs::remove_file(self.dir.join(&entry.file_name));
+            }
+            index.entries.clear();
+            self.persist_index(&index);
+        }
+    }
+}
+
+#[tauri::command]
+pub fn clear_preview_cache(cache: tauri::State<'_, PreviewCache>) -> Result<(), String> {
+    cache.clear();
+    Ok(())
+}
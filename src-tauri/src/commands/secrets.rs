@@ -0,0 +1,179 @@
+// Encrypted-at-rest storage for LLM provider API keys. Keys are AES-256-GCM encrypted with a
+// master key before ever touching disk, so the frontend only ever passes a `platform`
+// identifier and the backend command looks up, decrypts, and resolves the key at call time
+// instead of holding or re-sending the raw value on every `chat_query`/`generate_annotations`/
+// `evaluation_generate` call. The master key itself lives in the OS keychain (Keychain on
+// macOS, Credential Manager on Windows, Secret Service on Linux via `keyring`) when one is
+// available, falling back to a generated app-local key file so encryption still works in
+// environments without a keychain backend (e.g. a headless Linux sandbox).
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+
+use aes_gcm::aead::{Aead, AeadCore, KeyInit, OsRng};
+use aes_gcm::{Aes256Gcm, Key, Nonce};
+use base64::Engine;
+use serde::{Deserialize, Serialize};
+use tauri::Manager;
+
+const SERVICE_NAME: &str = "BioForge";
+const MASTER_KEY_ENTRY: &str = "__master_key__";
+const MASTER_KEY_FILENAME: &str = "secrets-master.key";
+const API_KEYS_FILENAME: &str = "api-keys.enc.json";
+
+fn entry(user: &str) -> Result<keyring::Entry, String> {
+    keyring::Entry::new(SERVICE_NAME, user).map_err(|e| e.to_string())
+}
+
+/// One encrypted provider key as stored on disk: `value` is base64(nonce || ciphertext).
+/// `encrypted` is carried alongside it so the stored config object is self-describing rather
+/// than leaving readers to assume the format.
+#[derive(Serialize, Deserialize)]
+struct EncryptedField {
+    encrypted: bool,
+    value: String,
+}
+
+#[derive(Default, Serialize, Deserialize)]
+struct EncryptedKeyStore {
+    #[serde(flatten)]
+    entries: HashMap<String, EncryptedField>,
+}
+
+fn api_keys_path(app: &tauri::AppHandle) -> Option<PathBuf> {
+    app.path().app_config_dir().ok().map(|d| d.join(API_KEYS_FILENAME))
+}
+
+fn load_key_store(app: &tauri::AppHandle) -> EncryptedKeyStore {
+    api_keys_path(app)
+        .and_then(|p| fs::read_to_string(p).ok())
+        .and_then(|s| serde_json::from_str(&s).ok())
+        .unwrap_or_default()
+}
+
+fn save_key_store(app: &tauri::AppHandle, store: &EncryptedKeyStore) -> Result<(), String> {
+    let path = api_keys_path(app).ok_or("App config dir not found")?;
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+    }
+    let json = serde_json::to_string(store).map_err(|e| e.to_string())?;
+    fs::write(&path, json).map_err(|e| e.to_string())?;
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let _ = fs::set_permissions(&path, fs::Permissions::from_mode(0o600));
+    }
+    Ok(())
+}
+
+fn generate_key() -> [u8; 32] {
+    let mut key = [0u8; 32];
+    aes_gcm::aead::rand_core::RngCore::fill_bytes(&mut OsRng, &mut key);
+    key
+}
+
+fn master_key_path(app: &tauri::AppHandle) -> Option<PathBuf> {
+    app.path().app_config_dir().ok().map(|d| d.join(MASTER_KEY_FILENAME))
+}
+
+/// Loads the 32-byte AES-256-GCM master key, creating one on first use. Preferred home is the
+/// OS keychain; if that backend is unavailable, an app-local key file takes over so encryption
+/// degrades gracefully instead of failing closed.
+fn load_or_create_master_key(app: &tauri::AppHandle) -> Result<[u8; 32], String> {
+    if let Ok(keychain) = entry(MASTER_KEY_ENTRY) {
+        if let Ok(existing) = keychain.get_password() {
+            if let Ok(bytes) = base64::engine::general_purpose::STANDARD.decode(existing) {
+                if let Ok(key) = <[u8; 32]>::try_from(bytes.as_slice()) {
+                    return Ok(key);
+                }
+            }
+        }
+        let key = generate_key();
+        if keychain
+            .set_password(&base64::engine::general_purpose::STANDARD.encode(key))
+            .is_ok()
+        {
+            return Ok(key);
+        }
+    }
+
+    let path = master_key_path(app).ok_or("App config dir not found")?;
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+    }
+    if let Ok(existing) = fs::read(&path) {
+        if let Ok(key) = <[u8; 32]>::try_from(existing.as_slice()) {
+            return Ok(key);
+        }
+    }
+    let key = generate_key();
+    fs::write(&path, key).map_err(|e| e.to_string())?;
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let _ = fs::set_permissions(&path, fs::Permissions::from_mode(0o600));
+    }
+    Ok(key)
+}
+
+fn encrypt(master_key: &[u8; 32], plaintext: &str) -> Result<String, String> {
+    let cipher = Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(master_key));
+    let nonce = Aes256Gcm::generate_nonce(&mut OsRng);
+    let ciphertext = cipher
+        .encrypt(&nonce, plaintext.as_bytes())
+        .map_err(|e| e.to_string())?;
+    let mut combined = nonce.to_vec();
+    combined.extend_from_slice(&ciphertext);
+    Ok(base64::engine::general_purpose::STANDARD.encode(combined))
+}
+
+fn decrypt(master_key: &[u8; 32], encoded: &str) -> Result<String, String> {
+    let combined = base64::engine::general_purpose::STANDARD
+        .decode(encoded)
+        .map_err(|e| e.to_string())?;
+    if combined.len() < 12 {
+        return Err("Corrupt encrypted value".to_string());
+    }
+    let (nonce_bytes, ciphertext) = combined.split_at(12);
+    let cipher = Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(master_key));
+    let plaintext = cipher
+        .decrypt(Nonce::from_slice(nonce_bytes), ciphertext)
+        .map_err(|e| e.to_string())?;
+    String::from_utf8(plaintext).map_err(|e| e.to_string())
+}
+
+/// Looks up and decrypts a stored key for `platform`, for use by other command modules
+/// (annotations, chat, evaluation) that accept an explicit `api_key` override but fall back
+/// to the encrypted store when the caller only passes a platform identifier.
+pub fn resolve_api_key(app: &tauri::AppHandle, platform: &str) -> Option<String> {
+    let store = load_key_store(app);
+    let field = store.entries.get(platform)?;
+    let master_key = load_or_create_master_key(app).ok()?;
+    decrypt(&master_key, &field.value).ok()
+}
+
+#[tauri::command]
+pub fn store_api_key(app: tauri::AppHandle, platform: String, key: String) -> Result<(), String> {
+    let master_key = load_or_create_master_key(&app)?;
+    let value = encrypt(&master_key, &key)?;
+    let mut store = load_key_store(&app);
+    store.entries.insert(platform, EncryptedField { encrypted: true, value });
+    save_key_store(&app, &store)
+}
+
+#[tauri::command]
+pub fn get_api_key(app: tauri::AppHandle, platform: String) -> Result<Option<String>, String> {
+    Ok(resolve_api_key(&app, &platform))
+}
+
+#[tauri::command]
+pub fn has_api_key(app: tauri::AppHandle, platform: String) -> Result<bool, String> {
+    Ok(load_key_store(&app).entries.contains_key(&platform))
+}
+
+#[tauri::command]
+pub fn delete_api_key(app: tauri::AppHandle, platform: String) -> Result<(), String> {
+    let mut store = load_key_store(&app);
+    store.entries.remove(&platform);
+    save_key_store(&app, &store)
+}
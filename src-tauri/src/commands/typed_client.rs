@@ -0,0 +1,56 @@
+//! Typed layer over `http::backend_json`.
+//!
+//! The plan (per the fatcat-api approach) is to generate request/response structs and a
+//! client from the backend's OpenAPI document at build time. That document doesn't exist in
+//! this tree yet — the Python backend has no `/openapi.json` route and there's no build.rs
+//! codegen step to consume it — so there is nothing to generate models *from* right now.
+//! This lands the typed-client plumbing that the generated code would sit on top of:
+//! `backend_json_typed` serializes a request struct, sends it through the existing
+//! breaker/retry-aware `backend_json`, and deserializes the envelope's `data` into a
+//! response struct instead of handing callers an opaque `serde_json::Value`. Once a spec is
+//! available, per-entity structs (e.g. `RuleCreate`/`Rule`) can be generated or hand-written
+//! and commands switched over one at a time without touching this helper. `commands::models`
+//! already carries a few of these hand-written structs (`KnowledgePoint`, `Document`,
+//! `FinetuningJob`, `Annotation`); `finetuning::get_job_status` is the first command built
+//! directly on top of them instead of passing through an opaque `serde_json::Value`.
+
+use reqwest::Method;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::commands::http::{self, BioError};
+
+pub async fn backend_json_typed<B, R>(
+    app: &tauri::AppHandle,
+    method: Method,
+    path: &str,
+    query: Option<Vec<(String, String)>>,
+    body: Option<&B>,
+) -> Result<R, BioError>
+where
+    B: Serialize,
+    R: DeserializeOwned,
+{
+    let body_value = match body {
+        Some(b) => Some(serde_json::to_value(b).map_err(|e| BioError::Parse { message: e.to_string() })?),
+        None => None,
+    };
+    let raw = http::backend_json(app, method, path, query, body_value)
+        .await
+        .map_err(|message| BioError::Network { message })?;
+    let envelope: serde_json::Value =
+        serde_json::from_str(&raw).map_err(|e| BioError::Parse { message: e.to_string() })?;
+
+    if envelope["success"].as_bool() != Some(true) {
+        if let Some(error) = envelope.get("error").filter(|e| !e.is_null()) {
+            if let Ok(typed) = serde_json::from_value::<BioError>(error.clone()) {
+                return Err(typed);
+            }
+        }
+        return Err(BioError::Parse {
+            message: "Backend reported failure with no structured error".to_string(),
+        });
+    }
+
+    serde_json::from_value(envelope["data"].clone()).map_err(|e| BioError::Parse { message: e.to_string() })
+}
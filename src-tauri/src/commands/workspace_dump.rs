@@ -0,0 +1,182 @@
+// Versioned export/import of the whole workspace (documents metadata, knowledge points,
+// training sets/items, mount points, storage config, audit/desensitization logs) into a
+// single self-describing archive, so a knowledge base can be backed up or moved between
+// machines in one step instead of through the per-entity get_*/save_* commands.
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+
+use crate::commands::http;
+use crate::commands::storage;
+
+/// Bump when the archive shape changes and add a branch to `migrate_dump` that upgrades a
+/// dump written at the previous version forward — readers should never need to special-case
+/// an old version beyond that one migration step.
+const CURRENT_SCHEMA_VERSION: u32 = 1;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorkspaceDump {
+    pub schema_version: u32,
+    #[serde(default)]
+    pub documents: serde_json::Value,
+    #[serde(default)]
+    pub knowledge_points: serde_json::Value,
+    #[serde(default)]
+    pub training_set: serde_json::Value,
+    #[serde(default)]
+    pub training_items: serde_json::Value,
+    #[serde(default)]
+    pub mount_points: serde_json::Value,
+    #[serde(default)]
+    pub storage_config: Option<serde_json::Value>,
+    #[serde(default)]
+    pub audit_log: serde_json::Value,
+    #[serde(default)]
+    pub desensitization_log: serde_json::Value,
+}
+
+/// Pulls the `data` field out of a `backend_json` envelope, falling back to `Value::Null` on
+/// a request error rather than failing the whole dump over one unreachable section.
+async fn fetch_section(app: &tauri::AppHandle, path: &str) -> serde_json::Value {
+    let Ok(raw) = http::backend_json(app, Method::GET, path, None, None).await else {
+        return serde_json::Value::Null;
+    };
+    serde_json::from_str::<serde_json::Value>(&raw)
+        .ok()
+        .and_then(|v| v.get("data").cloned())
+        .unwrap_or(serde_json::Value::Null)
+}
+
+/// True when a `backend_json` envelope reports success. `backend_json` resolves to `Ok` for
+/// most backend-reported failures too (success/failure lives inside the JSON, not the Rust
+/// `Result`), so a restored section must gate on this rather than on the outer `Result`.
+fn envelope_succeeded(raw: &str) -> bool {
+    serde_json::from_str::<serde_json::Value>(raw)
+        .map(|envelope| envelope["success"].as_bool() == Some(true))
+        .unwrap_or(false)
+}
+
+#[tauri::command]
+pub async fn export_workspace_dump(app: tauri::AppHandle) -> Result<String, String> {
+    let dump = WorkspaceDump {
+        schema_version: CURRENT_SCHEMA_VERSION,
+        documents: fetch_section(&app, "/documents").await,
+        knowledge_points: fetch_section(&app, "/documents/knowledge-points?page=1&page_size=100000").await,
+        training_set: fetch_section(&app, "/training-set").await,
+        training_items: fetch_section(&app, "/training-items").await,
+        mount_points: fetch_section(&app, "/mount-points").await,
+        storage_config: storage::get_storage_config(app.clone()).ok().flatten(),
+        audit_log: fetch_section(&app, "/audit-log?limit=100000").await,
+        desensitization_log: fetch_section(&app, "/desensitization-log?limit=100000").await,
+    };
+    serde_json::to_string(&dump).map_err(|e| e.to_string())
+}
+
+/// Upgrades a parsed dump in place from whatever `schema_version` it was written at to
+/// `CURRENT_SCHEMA_VERSION`. A no-op today since there's only ever been one version; new
+/// fields land here as `v1 -> v2`, `v2 -> v3`, etc., each step filling in whatever the older
+/// archive didn't have rather than rejecting it.
+fn migrate_dump(dump: &mut WorkspaceDump) -> Result<(), String> {
+    if dump.schema_version > CURRENT_SCHEMA_VERSION {
+        return Err(format!(
+            "Dump schema version {} is newer than this build supports ({})",
+            dump.schema_version, CURRENT_SCHEMA_VERSION
+        ));
+    }
+    dump.schema_version = CURRENT_SCHEMA_VERSION;
+    Ok(())
+}
+
+/// Restores every section that has a natural create/save endpoint (training items, the
+/// training set, mount points, storage config). Documents and knowledge points are not
+/// re-created: document content can't be reconstructed from metadata alone, and knowledge
+/// points need their source document, so both sections are reported as skipped rather than
+/// silently dropped or failed over. Audit/desensitization logs are history, not state, and are
+/// export-only.
+#[tauri::command]
+pub async fn import_workspace_dump(app: tauri::AppHandle, dump_json: String) -> Result<String, String> {
+    let mut dump: WorkspaceDump = serde_json::from_str(&dump_json).map_err(|e| e.to_string())?;
+    migrate_dump(&mut dump)?;
+
+    let mut restored = Vec::new();
+    let mut skipped = Vec::new();
+
+    if let Some(config) = &dump.storage_config {
+        if let (Some(documents_dir), Some(db_path)) = (
+            config.get("documentsDir").and_then(|v| v.as_str()),
+            config.get("dbPath").and_then(|v| v.as_str()),
+        ) {
+            if storage::save_storage_config(app.clone(), documents_dir.to_string(), db_path.to_string()).is_ok() {
+                restored.push("storage_config");
+            }
+        }
+    }
+
+    if let serde_json::Value::Array(items) = &dump.training_items {
+        let mut all_succeeded = !items.is_empty();
+        for item in items {
+            let name = item.get("name").and_then(|v| v.as_str()).unwrap_or_default().to_string();
+            let prompt_template = item
+                .get("prompt_template")
+                .and_then(|v| v.as_str())
+                .unwrap_or_default()
+                .to_string();
+            let keys: Vec<String> = item
+                .get("knowledge_point_keys")
+                .and_then(|v| v.as_array())
+                .map(|arr| arr.iter().filter_map(|v| v.as_str().map(str::to_string)).collect())
+                .unwrap_or_default();
+            let payload = serde_json::json!({
+                "name": name,
+                "knowledge_point_keys": keys,
+                "prompt_template": prompt_template
+            });
+            let result = http::backend_json(&app, Method::POST, "/training-items", None, Some(payload)).await;
+            all_succeeded &= result.as_deref().map(envelope_succeeded).unwrap_or(false);
+        }
+        if all_succeeded {
+            restored.push("training_items");
+        }
+    }
+
+    if !dump.training_set.is_null() {
+        let payload = serde_json::json!({ "annotations": dump.training_set, "training_item_id": null });
+        let result = http::backend_json(&app, Method::POST, "/training-set", None, Some(payload)).await;
+        if result.as_deref().map(envelope_succeeded).unwrap_or(false) {
+            restored.push("training_set");
+        }
+    }
+
+    if let serde_json::Value::Array(mps) = &dump.mount_points {
+        let mut all_succeeded = !mps.is_empty();
+        for mp in mps {
+            let path = mp.get("path").and_then(|v| v.as_str()).unwrap_or_default().to_string();
+            if path.is_empty() {
+                continue;
+            }
+            let name = mp.get("name").and_then(|v| v.as_str()).map(str::to_string);
+            let description = mp.get("description").and_then(|v| v.as_str()).map(str::to_string);
+            let payload = serde_json::json!({
+                "path": path,
+                "name": name.unwrap_or_default(),
+                "description": description.unwrap_or_default()
+            });
+            let result = http::backend_json(&app, Method::POST, "/mount-points", None, Some(payload)).await;
+            all_succeeded &= result.as_deref().map(envelope_succeeded).unwrap_or(false);
+        }
+        if all_succeeded {
+            restored.push("mount_points");
+        }
+    }
+
+    if !dump.documents.is_null() {
+        skipped.push("documents (no original files to re-upload)");
+    }
+    if !dump.knowledge_points.is_null() {
+        skipped.push("knowledge_points (require their source document to already exist)");
+    }
+    if !dump.audit_log.is_null() || !dump.desensitization_log.is_null() {
+        skipped.push("audit_log/desensitization_log (history, export-only)");
+    }
+
+    serde_json::to_string(&serde_json::json!({ "restored": restored, "skipped": skipped })).map_err(|e| e.to_string())
+}
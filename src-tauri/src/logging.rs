@@ -0,0 +1,60 @@
+// Structured tracing subsystem: logs to a rotating daily file under the app's log dir (and
+// not to stdout), so a crash report can ship the actual log file rather than whatever
+// happened to be visible in a terminal.
+use std::path::PathBuf;
+
+use tauri::Manager;
+use tracing_appender::non_blocking::WorkerGuard;
+use tracing_subscriber::EnvFilter;
+
+const LOG_FILE_PREFIX: &str = "bioforger";
+
+/// Keeps the non-blocking file writer alive; dropping this flushes and stops it, so it must
+/// be held in managed state for the lifetime of the app.
+pub struct LoggingGuard(#[allow(dead_code)] WorkerGuard);
+
+pub fn log_dir(app: &tauri::AppHandle) -> Option<PathBuf> {
+    app.path().app_log_dir().ok()
+}
+
+/// Installs the global tracing subscriber. Safe to call once at startup; the returned guard
+/// must be `app.manage()`d so the background writer thread isn't dropped immediately.
+pub fn init(app: &tauri::AppHandle) -> Option<LoggingGuard> {
+    let dir = log_dir(app)?;
+    std::fs::create_dir_all(&dir).ok()?;
+    let file_appender = tracing_appender::rolling::daily(&dir, LOG_FILE_PREFIX);
+    let (non_blocking, guard) = tracing_appender::non_blocking(file_appender);
+
+    let filter = EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("info"));
+    tracing_subscriber::fmt()
+        .with_env_filter(filter)
+        .with_ansi(false)
+        .with_writer(non_blocking)
+        .init();
+
+    Some(LoggingGuard(guard))
+}
+
+/// Returns the last `max_lines` lines of the most recent log file, for an in-app diagnostics
+/// panel or to attach to a bug report. Empty string if logging hasn't produced a file yet.
+///
+/// `tracing_appender::rolling::daily` names files `<prefix>.YYYY-MM-DD`; rather than pull in
+/// a date/time crate (none is used elsewhere in this crate) just to compute "today", we pick
+/// whichever file under the log dir was written to most recently.
+pub fn tail_today(app: &tauri::AppHandle, max_lines: usize) -> String {
+    let Some(dir) = log_dir(app) else { return String::new() };
+    let Ok(entries) = std::fs::read_dir(&dir) else { return String::new() };
+
+    let latest = entries
+        .filter_map(|e| e.ok())
+        .filter(|e| e.file_name().to_string_lossy().starts_with(LOG_FILE_PREFIX))
+        .max_by_key(|e| e.metadata().and_then(|m| m.modified()).ok());
+
+    let Some(entry) = latest else { return String::new() };
+    let Ok(contents) = std::fs::read_to_string(entry.path()) else {
+        return String::new();
+    };
+    let lines: Vec<&str> = contents.lines().collect();
+    let start = lines.len().saturating_sub(max_lines);
+    lines[start..].join("\n")
+}
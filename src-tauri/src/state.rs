@@ -2,6 +2,8 @@
 use std::process::Child;
 use std::sync::Mutex;
 
+use serde::Serialize;
+
 #[cfg(windows)]
 pub(crate) struct JobHandleGuard(pub(crate) windows::Win32::Foundation::HANDLE);
 
@@ -24,12 +26,102 @@ pub struct BackendProcess {
     pub child: Child,
     #[cfg(windows)]
     pub(crate) _job: Option<JobHandleGuard>,
+    /// Process group id of the spawned child on Unix (it's its own session leader via
+    /// `setsid`), so stopping it can signal the whole group instead of just the one pid.
+    #[cfg(not(windows))]
+    pub(crate) pgid: Option<i32>,
+}
+
+/// Unix counterpart to the Windows Job Object's kill-on-close: if a `BackendProcess` is ever
+/// dropped without going through `stop_backend_process` first (a panic unwind, the struct being
+/// replaced mid-restart, the whole app process exiting), the backend's process group still gets
+/// a `SIGKILL` rather than being orphaned. Best-effort only — no waiting, since this can run
+/// during unwind.
+#[cfg(not(windows))]
+impl Drop for BackendProcess {
+    fn drop(&mut self) {
+        if let Some(pgid) = self.pgid {
+            unsafe {
+                libc::kill(-pgid, libc::SIGKILL);
+            }
+        }
+    }
 }
 
 pub struct BackendState {
     pub process: Mutex<Option<BackendProcess>>,
 }
 
+pub struct OllamaProcess {
+    pub child: Child,
+    /// Process group id of the spawned `ollama serve` on Unix (its own session leader via
+    /// `setsid`), mirroring `BackendProcess::pgid` so the same kill-on-exit parity applies.
+    #[cfg(not(windows))]
+    pub(crate) pgid: Option<i32>,
+}
+
+/// See `BackendProcess`'s `Drop` impl — same kill-on-exit parity for the Ollama sidecar.
+#[cfg(not(windows))]
+impl Drop for OllamaProcess {
+    fn drop(&mut self) {
+        if let Some(pgid) = self.pgid {
+            unsafe {
+                libc::kill(-pgid, libc::SIGKILL);
+            }
+        }
+    }
+}
+
 pub struct OllamaState {
-    pub process: Mutex<Option<Child>>,
+    pub process: Mutex<Option<OllamaProcess>>,
+}
+
+/// Lifecycle phase of a supervised child process (the Python backend or Ollama), tracked by
+/// the supervisors in `commands::backend_lifecycle` and `commands::ollama` and mirrored to the
+/// frontend via `backend-status`/`ollama-status` events.
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum SupervisedPhase {
+    Starting,
+    Ready,
+    Degraded,
+    Restarting,
+    Stopped,
+    /// The supervisor hit `SUPERVISOR_MAX_RETRIES` consecutive failed restart attempts and has
+    /// stopped trying to relaunch the child; it keeps polling `/health` so an externally
+    /// recovered process still brings the snapshot back to `Ready`.
+    Failed,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SupervisorSnapshot {
+    pub phase: SupervisedPhase,
+    pub pid: Option<u32>,
+    pub port: u16,
+    pub restarts: u32,
+    pub last_restart_unix_ms: Option<u64>,
+    pub last_healthy_unix_ms: Option<u64>,
+    pub last_error: Option<String>,
+}
+
+impl SupervisorSnapshot {
+    pub fn starting(port: u16) -> Self {
+        SupervisorSnapshot {
+            phase: SupervisedPhase::Starting,
+            pid: None,
+            port,
+            restarts: 0,
+            last_restart_unix_ms: None,
+            last_healthy_unix_ms: None,
+            last_error: None,
+        }
+    }
+}
+
+pub struct BackendSupervisorState {
+    pub snapshot: Mutex<SupervisorSnapshot>,
+}
+
+pub struct OllamaSupervisorState {
+    pub snapshot: Mutex<SupervisorSnapshot>,
 }